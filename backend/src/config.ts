@@ -25,8 +25,6 @@ export const DELEGATION_CHECK_INTERVAL_MS = 2000; // how often to check isDelega
 
 // ─── On-chain constants (mirrored from state.rs) ────────────
 
-export const MAX_BOMBS = 12;
-export const EXPLOSION_DURATION_SLOTS = 5;
 export const STATUS_LOBBY = 0;
 export const STATUS_ACTIVE = 1;
 export const STATUS_FINISHED = 2;
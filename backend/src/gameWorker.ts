@@ -11,7 +11,6 @@ import {
   STATUS_ACTIVE,
   STATUS_FINISHED,
   STATUS_CLAIMED,
-  MAX_BOMBS,
 } from "./config";
 import {
   fetchFullGameState,
@@ -165,10 +164,11 @@ export class GameWorker {
     const now = Date.now();
     if (now - this.lastCrankTime < CRANK_COOLDOWN_MS) return;
 
-    const playerPdas = getAllPlayerPdas(this.gamePda, this.maxPlayers);
+    // Detonation takes exactly the joined players, in index order
+    const playerPdas = getAllPlayerPdas(this.gamePda, state.game.currentPlayers);
     const expiredIndices: number[] = [];
 
-    for (let i = 0; i < MAX_BOMBS; i++) {
+    for (let i = 0; i < state.game.bombs.length; i++) {
       const bomb = state.game.bombs[i];
       if (!bomb || !bomb.active || bomb.detonated) continue;
 
@@ -206,7 +206,8 @@ export class GameWorker {
 
     // Only 0 or 1 alive — time to end
     this.checkGameEndSent = true;
-    const playerPdas = getAllPlayerPdas(this.gamePda, this.maxPlayers);
+    // The program requires exactly the joined players, in index order
+    const playerPdas = getAllPlayerPdas(this.gamePda, state.game.currentPlayers);
     const sig = await sendCheckGameEnd(this.gamePda, playerPdas, this._delegated);
 
     if (sig) {
@@ -389,11 +390,11 @@ function toWireState(
   }
 
   const bombs: WireBombState[] = [];
-  for (let i = 0; i < MAX_BOMBS; i++) {
+  for (let i = 0; i < g.bombs.length; i++) {
     const b = g.bombs[i];
     if (b && (b.active || b.detonated)) {
       bombs.push({
-        owner: pubkeyOrNull(b.owner),
+        ownerIndex: b.ownerIndex,
         x: b.x,
         y: b.y,
         range: b.range,
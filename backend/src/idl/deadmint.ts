@@ -14,16 +14,20 @@ export type Deadmint = {
   },
   "instructions": [
     {
-      "name": "checkGameEnd",
+      "name": "cancelGame",
+      "docs": [
+        "Cancel a lobby that never filled, refunding every wager and closing all accounts.",
+        "Remaining accounts: `[player_pda, payer_wallet]` for each joined player."
+      ],
       "discriminator": [
-        86,
-        79,
-        62,
-        221,
-        255,
-        185,
-        211,
-        253
+        121,
+        194,
+        154,
+        118,
+        103,
+        235,
+        149,
+        52
       ],
       "accounts": [
         {
@@ -50,22 +54,72 @@ export type Deadmint = {
         },
         {
           "name": "authority",
+          "writable": true
+        },
+        {
+          "name": "caller",
+          "docs": [
+            "The game authority at any time, or anyone once the lobby has expired"
+          ],
           "signer": true
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authorityTokenAccount",
+          "docs": [
+            "Creator's token account for any leftover vault balance"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
         }
       ],
       "args": []
     },
     {
-      "name": "claimPrize",
+      "name": "checkGameEnd",
+      "docs": [
+        "End the game once one player (or none) is left or the round timer runs out.",
+        "Remaining accounts: every Player PDA of the game, in index order."
+      ],
       "discriminator": [
-        157,
-        233,
-        139,
-        121,
-        246,
+        86,
+        79,
         62,
-        234,
-        235
+        221,
+        255,
+        185,
+        211,
+        253
       ],
       "accounts": [
         {
@@ -91,208 +145,117 @@ export type Deadmint = {
           }
         },
         {
-          "name": "player"
-        },
-        {
-          "name": "winner",
-          "docs": [
-            "The winner's wallet (receives SOL payout)"
-          ],
-          "writable": true,
+          "name": "authority",
           "signer": true
         }
       ],
       "args": []
     },
     {
-      "name": "delegate",
+      "name": "claimLoot",
       "docs": [
-        "Delegate a PDA (Game or Player) to the Ephemeral Rollup validator.",
-        "Seeds are passed as instruction data so the SDK can verify PDA ownership."
+        "Pay out a player's collected loot once the game has finished."
       ],
       "discriminator": [
-        90,
-        147,
-        75,
-        178,
+        69,
+        105,
+        8,
+        13,
+        65,
+        21,
         85,
-        88,
-        4,
-        137
+        238
       ],
       "accounts": [
         {
-          "name": "payer",
-          "signer": true
-        },
-        {
-          "name": "bufferPda",
+          "name": "game",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  117,
-                  102,
-                  102,
-                  101,
-                  114
+                  103,
+                  97,
+                  109,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "pda"
+                "path": "game.game_id",
+                "account": "game"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                144,
-                125,
-                116,
-                254,
-                115,
-                159,
-                220,
-                207,
-                113,
-                184,
-                36,
-                252,
-                85,
-                221,
-                29,
-                139,
-                203,
-                77,
-                128,
-                151,
-                30,
-                123,
-                190,
-                92,
-                225,
-                235,
-                177,
-                68,
-                211,
-                125,
-                120,
-                164
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "delegationRecordPda",
+          "name": "player",
+          "writable": true
+        },
+        {
+          "name": "payer",
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  100,
-                  101,
-                  108,
-                  101,
-                  103,
-                  97,
-                  116,
-                  105,
-                  111,
-                  110
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "pda"
-              }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "delegationProgram"
-            }
-          }
+          "relations": [
+            "player"
+          ]
         },
         {
-          "name": "delegationMetadataPda",
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "vault",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  101,
-                  108,
-                  101,
-                  103,
-                  97,
-                  116,
-                  105,
-                  111,
-                  110,
-                  45,
-                  109,
-                  101,
-                  116,
-                  97,
-                  100,
+                  118,
                   97,
-                  116,
-                  97
+                  117,
+                  108,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "pda"
+                "path": "game"
               }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "delegationProgram"
-            }
+            ]
           }
         },
         {
-          "name": "pda",
-          "writable": true
-        },
-        {
-          "name": "ownerProgram",
-          "address": "Hx7eQa2NhDDKiBThKyo4VNLnBi7pApQX9JZTsA5xBbdb"
-        },
-        {
-          "name": "delegationProgram",
-          "address": "DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh"
+          "name": "payerTokenAccount",
+          "docs": [
+            "Player's token account (owned by `payer`)"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "tokenProgram",
+          "optional": true
         }
       ],
-      "args": [
-        {
-          "name": "seeds",
-          "type": {
-            "vec": "bytes"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "detonateBomb",
+      "name": "claimPrize",
+      "docs": [
+        "Pay a placed player (winner, runner-up, tied survivors...) their share of the pool."
+      ],
       "discriminator": [
-        33,
-        102,
-        122,
-        203,
-        170,
-        213,
-        123,
-        58
+        157,
+        233,
+        139,
+        121,
+        246,
+        62,
+        234,
+        235
       ],
       "accounts": [
         {
@@ -318,90 +281,131 @@ export type Deadmint = {
           }
         },
         {
-          "name": "authority",
+          "name": "player"
+        },
+        {
+          "name": "treasury",
           "docs": [
-            "Anyone can call detonate (crank-able). Payer just pays tx fee."
+            "Protocol treasury (receives the platform fee)"
           ],
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "bombIndex",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "initializeGame",
-      "discriminator": [
-        44,
-        62,
-        102,
-        247,
-        126,
-        208,
-        130,
-        215
-      ],
-      "accounts": [
-        {
-          "name": "game",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
+                  116,
+                  114,
+                  101,
                   97,
-                  109,
-                  101
+                  115,
+                  117,
+                  114,
+                  121
                 ]
-              },
-              {
-                "kind": "arg",
-                "path": "gameId"
               }
             ]
           }
         },
         {
           "name": "payer",
+          "docs": [
+            "Bound to the Player account, so a session key cannot redirect it."
+          ],
           "writable": true,
-          "signer": true
+          "relations": [
+            "player"
+          ]
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
+          "name": "mint",
+          "optional": true
+        },
         {
-          "name": "gameId",
-          "type": "u64"
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game"
+              }
+            ]
+          }
         },
         {
-          "name": "entryFee",
-          "type": "u64"
+          "name": "payerTokenAccount",
+          "docs": [
+            "Player's token account (owned by `payer`)"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "maxPlayers",
-          "type": "u8"
+          "name": "treasuryVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.mint",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "joinGame",
+      "name": "closeGame",
+      "docs": [
+        "Close a settled Game account once all its Player accounts are closed."
+      ],
       "discriminator": [
-        107,
-        112,
-        18,
-        38,
-        56,
-        173,
-        60,
-        128
+        237,
+        236,
+        157,
+        201,
+        253,
+        20,
+        248,
+        67
       ],
       "accounts": [
         {
@@ -427,88 +431,65 @@ export type Deadmint = {
           }
         },
         {
-          "name": "player",
+          "name": "authority",
           "writable": true
         },
         {
-          "name": "payer",
-          "writable": true,
-          "signer": true
+          "name": "mint",
+          "optional": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "playerAuthority",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "movePlayer",
-      "discriminator": [
-        17,
-        58,
-        68,
-        221,
-        186,
-        117,
-        140,
-        231
-      ],
-      "accounts": [
-        {
-          "name": "game",
+          "name": "vault",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
+                  118,
                   97,
-                  109,
-                  101
+                  117,
+                  108,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "game.game_id",
-                "account": "game"
+                "path": "game"
               }
             ]
           }
         },
         {
-          "name": "player",
-          "writable": true
+          "name": "authorityTokenAccount",
+          "docs": [
+            "Creator's token account for any leftover vault balance"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "authority",
-          "signer": true
+          "name": "tokenProgram",
+          "optional": true
         }
       ],
-      "args": [
-        {
-          "name": "direction",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "placeBomb",
+      "name": "closePlayer",
+      "docs": [
+        "Close a Player account after settlement, returning rent to its payer."
+      ],
       "discriminator": [
-        165,
-        31,
-        36,
-        156,
-        19,
-        206,
-        89,
-        188
+        225,
+        227,
+        204,
+        63,
+        32,
+        122,
+        58,
+        227
       ],
       "accounts": [
         {
@@ -538,155 +519,1737 @@ export type Deadmint = {
           "writable": true
         },
         {
-          "name": "authority",
-          "signer": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "processUndelegation",
-      "discriminator": [
-        196,
-        28,
-        41,
-        206,
-        48,
-        37,
-        51,
-        167
-      ],
-      "accounts": [
+          "name": "payer",
+          "writable": true,
+          "relations": [
+            "player"
+          ]
+        },
         {
-          "name": "baseAccount",
-          "writable": true
+          "name": "mint",
+          "optional": true
         },
         {
-          "name": "buffer"
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game"
+              }
+            ]
+          }
         },
         {
-          "name": "payer",
-          "writable": true
+          "name": "payerTokenAccount",
+          "docs": [
+            "Player's token account for unclaimed loot (owned by `payer`)"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "systemProgram"
+          "name": "tokenProgram",
+          "optional": true
         }
       ],
-      "args": [
-        {
-          "name": "accountSeeds",
-          "type": {
-            "vec": "bytes"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "undelegate",
+      "name": "delegate",
       "docs": [
-        "Commit state and undelegate a PDA from the Ephemeral Rollup."
+        "Delegate a PDA (Game or Player) to the Ephemeral Rollup validator.",
+        "Seeds are passed as instruction data so the SDK can verify PDA ownership."
       ],
       "discriminator": [
-        131,
-        148,
-        180,
-        198,
-        91,
-        104,
-        42,
-        238
+        90,
+        147,
+        75,
+        178,
+        85,
+        88,
+        4,
+        137
       ],
       "accounts": [
         {
           "name": "payer",
-          "writable": true,
           "signer": true
         },
         {
-          "name": "pda",
-          "writable": true
-        },
-        {
-          "name": "magicProgram",
-          "address": "Magic11111111111111111111111111111111111111"
+          "name": "bufferPda",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pda"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                251,
+                217,
+                114,
+                226,
+                242,
+                54,
+                205,
+                108,
+                169,
+                206,
+                21,
+                107,
+                244,
+                181,
+                107,
+                108,
+                175,
+                133,
+                153,
+                251,
+                145,
+                238,
+                222,
+                76,
+                188,
+                106,
+                244,
+                11,
+                251,
+                201,
+                116,
+                210
+              ]
+            }
+          }
         },
         {
-          "name": "magicContext",
+          "name": "delegationRecordPda",
           "writable": true,
-          "address": "MagicContext1111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "game",
-      "discriminator": [
-        27,
-        90,
-        166,
-        125,
-        74,
-        100,
-        121,
-        18
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pda"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "delegationProgram"
+            }
+          }
+        },
+        {
+          "name": "delegationMetadataPda",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pda"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "delegationProgram"
+            }
+          }
+        },
+        {
+          "name": "pda",
+          "writable": true
+        },
+        {
+          "name": "ownerProgram",
+          "address": "Hx7eQa2NhDDKiBThKyo4VNLnBi7pApQX9JZTsA5xBbdb"
+        },
+        {
+          "name": "delegationProgram",
+          "address": "DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "seeds",
+          "type": {
+            "vec": "bytes"
+          }
+        }
       ]
     },
     {
-      "name": "player",
+      "name": "detonateBomb",
+      "docs": [
+        "Detonate a bomb whose fuse has run out, along with every bomb it chains into.",
+        "Remaining accounts: every Player PDA of the game, in index order."
+      ],
       "discriminator": [
-        205,
-        222,
-        112,
-        7,
-        165,
-        155,
-        206,
-        218
+        33,
+        102,
+        122,
+        203,
+        170,
+        213,
+        123,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.game_id",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Anyone can call detonate (crank-able). Payer just pays tx fee."
+          ],
+          "signer": true
+        },
+        {
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "bombIndex",
+          "type": "u8"
+        }
       ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "gameNotInLobby",
-      "msg": "Game is not in lobby state"
-    },
-    {
-      "code": 6001,
-      "name": "gameNotActive",
-      "msg": "Game is not active"
-    },
-    {
-      "code": 6002,
-      "name": "gameNotFinished",
-      "msg": "Game is not finished"
-    },
-    {
-      "code": 6003,
-      "name": "gameFull",
-      "msg": "Game is full"
-    },
-    {
-      "code": 6004,
-      "name": "playerNotAlive",
-      "msg": "Player is not alive"
-    },
-    {
-      "code": 6005,
-      "name": "playerGameMismatch",
-      "msg": "Player does not belong to this game"
     },
     {
-      "code": 6006,
-      "name": "unauthorized",
-      "msg": "Unauthorized — signer does not match player authority"
+      "name": "initializeConfig",
+      "docs": [
+        "One-time setup of the protocol config PDA (fee cap, entry-fee range, pause switch), by the upgrade authority."
+      ],
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "program",
+          "address": "Hx7eQa2NhDDKiBThKyo4VNLnBi7pApQX9JZTsA5xBbdb"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "configParams"
+            }
+          }
+        }
+      ]
     },
     {
-      "code": 6007,
-      "name": "invalidDirection",
-      "msg": "Invalid direction (must be 0-3)"
+      "name": "initializeGame",
+      "discriminator": [
+        44,
+        62,
+        102,
+        247,
+        126,
+        208,
+        130,
+        215
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "SPL Token or Token-2022 mint for token-denominated wagers (omit for native SOL)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Game-owned vault that holds the token stake (only with `mint`)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryVault",
+          "docs": [
+            "Treasury vault for `mint`, which receives the platform fee (only with `mint`)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "gameParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeTreasury",
+      "docs": [
+        "One-time setup of the treasury PDA that accrues platform fees, by the upgrade authority."
+      ],
+      "discriminator": [
+        124,
+        186,
+        211,
+        195,
+        85,
+        165,
+        129,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "program",
+          "address": "Hx7eQa2NhDDKiBThKyo4VNLnBi7pApQX9JZTsA5xBbdb"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeTreasuryVault",
+      "docs": [
+        "Config-admin-only creation of the treasury token account for an SPL mint."
+      ],
+      "discriminator": [
+        156,
+        109,
+        74,
+        13,
+        14,
+        144,
+        172,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "treasuryVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "joinGame",
+      "docs": [
+        "Join a lobby with a session key, contributing 32 bytes of entropy to the game seed."
+      ],
+      "discriminator": [
+        107,
+        112,
+        18,
+        38,
+        56,
+        173,
+        60,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.game_id",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payerTokenAccount",
+          "docs": [
+            "Payer's token account the entry fee is taken from"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "playerAuthority",
+          "type": "pubkey"
+        },
+        {
+          "name": "entropy",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "leaveLobby",
+      "docs": [
+        "Leave a lobby before it starts, refunding the wager to the paying wallet."
+      ],
+      "discriminator": [
+        197,
+        5,
+        184,
+        149,
+        30,
+        42,
+        230,
+        118
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.game_id",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "docs": [
+            "The leaving player"
+          ],
+          "writable": true
+        },
+        {
+          "name": "lastPlayer",
+          "docs": [
+            "The most recently joined player, moved into the leaver's slot.",
+            "Omit when the leaver is the last player."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Wallet that paid the wager (receives the refund and rent)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payerTokenAccount",
+          "docs": [
+            "Payer's token account for the refund"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "movePlayer",
+      "discriminator": [
+        17,
+        58,
+        68,
+        221,
+        186,
+        117,
+        140,
+        231
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.game_id",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "direction",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "placeBomb",
+      "discriminator": [
+        165,
+        31,
+        36,
+        156,
+        19,
+        206,
+        89,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.game_id",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "processUndelegation",
+      "discriminator": [
+        196,
+        28,
+        41,
+        206,
+        48,
+        37,
+        51,
+        167
+      ],
+      "accounts": [
+        {
+          "name": "baseAccount",
+          "writable": true
+        },
+        {
+          "name": "buffer"
+        },
+        {
+          "name": "payer",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "accountSeeds",
+          "type": {
+            "vec": "bytes"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMap",
+      "docs": [
+        "Replace the lobby's arena with a hand-designed map before anyone joins.",
+        "Only the game authority can call it; the map is validated and its hash stored."
+      ],
+      "discriminator": [
+        180,
+        73,
+        92,
+        79,
+        144,
+        196,
+        181,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.game_id",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "game"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "layout",
+          "type": {
+            "defined": {
+              "name": "mapLayout"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferAdmin",
+      "docs": [
+        "Hand the config admin role, treasury withdrawals included, to a new key (which must co-sign)."
+      ],
+      "discriminator": [
+        42,
+        242,
+        66,
+        106,
+        228,
+        10,
+        111,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "newAdmin",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "undelegate",
+      "docs": [
+        "Commit state and undelegate a PDA from the Ephemeral Rollup."
+      ],
+      "discriminator": [
+        131,
+        148,
+        180,
+        198,
+        91,
+        104,
+        42,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "pda",
+          "writable": true
+        },
+        {
+          "name": "magicProgram",
+          "address": "Magic11111111111111111111111111111111111111"
+        },
+        {
+          "name": "magicContext",
+          "writable": true,
+          "address": "MagicContext1111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateConfig",
+      "docs": [
+        "Admin-only update of the protocol config, including pausing and unpausing."
+      ],
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "configParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawFees",
+      "docs": [
+        "Config-admin-only withdrawal of accrued platform fees (SOL, or one SPL mint)."
+      ],
+      "discriminator": [
+        198,
+        212,
+        171,
+        109,
+        144,
+        215,
+        174,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "recipient",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipientTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "discriminator": [
+        155,
+        12,
+        170,
+        224,
+        30,
+        250,
+        204,
+        130
+      ]
+    },
+    {
+      "name": "game",
+      "discriminator": [
+        27,
+        90,
+        166,
+        125,
+        74,
+        100,
+        121,
+        18
+      ]
+    },
+    {
+      "name": "player",
+      "discriminator": [
+        205,
+        222,
+        112,
+        7,
+        165,
+        155,
+        206,
+        218
+      ]
+    },
+    {
+      "name": "treasury",
+      "discriminator": [
+        238,
+        239,
+        123,
+        238,
+        89,
+        1,
+        168,
+        253
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "bombExploded",
+      "discriminator": [
+        134,
+        100,
+        56,
+        206,
+        220,
+        154,
+        92,
+        210
+      ]
+    },
+    {
+      "name": "bombPlaced",
+      "discriminator": [
+        169,
+        114,
+        227,
+        13,
+        240,
+        81,
+        98,
+        221
+      ]
+    },
+    {
+      "name": "gameCancelled",
+      "discriminator": [
+        113,
+        20,
+        200,
+        104,
+        76,
+        35,
+        9,
+        241
+      ]
+    },
+    {
+      "name": "gameClosed",
+      "discriminator": [
+        178,
+        203,
+        179,
+        224,
+        43,
+        18,
+        209,
+        4
+      ]
+    },
+    {
+      "name": "gameCreated",
+      "discriminator": [
+        218,
+        25,
+        150,
+        94,
+        177,
+        112,
+        96,
+        2
+      ]
+    },
+    {
+      "name": "gameEnded",
+      "discriminator": [
+        35,
+        93,
+        113,
+        153,
+        29,
+        144,
+        200,
+        109
+      ]
+    },
+    {
+      "name": "gameStarted",
+      "discriminator": [
+        222,
+        247,
+        78,
+        255,
+        61,
+        184,
+        156,
+        41
+      ]
+    },
+    {
+      "name": "lootClaimed",
+      "discriminator": [
+        238,
+        46,
+        74,
+        46,
+        96,
+        127,
+        116,
+        4
+      ]
+    },
+    {
+      "name": "lootCollected",
+      "discriminator": [
+        248,
+        141,
+        71,
+        197,
+        171,
+        79,
+        166,
+        249
+      ]
+    },
+    {
+      "name": "mapSet",
+      "discriminator": [
+        211,
+        216,
+        32,
+        203,
+        42,
+        137,
+        0,
+        66
+      ]
+    },
+    {
+      "name": "playerClosed",
+      "discriminator": [
+        21,
+        199,
+        169,
+        47,
+        52,
+        171,
+        62,
+        72
+      ]
+    },
+    {
+      "name": "playerJoined",
+      "discriminator": [
+        39,
+        144,
+        49,
+        106,
+        108,
+        210,
+        183,
+        38
+      ]
+    },
+    {
+      "name": "playerKilled",
+      "discriminator": [
+        252,
+        86,
+        236,
+        138,
+        23,
+        59,
+        63,
+        110
+      ]
+    },
+    {
+      "name": "playerLeft",
+      "discriminator": [
+        7,
+        106,
+        62,
+        150,
+        175,
+        170,
+        96,
+        84
+      ]
+    },
+    {
+      "name": "playerMoved",
+      "discriminator": [
+        167,
+        114,
+        108,
+        144,
+        204,
+        62,
+        98,
+        128
+      ]
+    },
+    {
+      "name": "powerupCollected",
+      "discriminator": [
+        65,
+        129,
+        229,
+        149,
+        126,
+        108,
+        100,
+        29
+      ]
+    },
+    {
+      "name": "prizeClaimed",
+      "discriminator": [
+        213,
+        150,
+        192,
+        76,
+        199,
+        33,
+        212,
+        38
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "gameNotInLobby",
+      "msg": "Game is not in lobby state"
+    },
+    {
+      "code": 6001,
+      "name": "gameNotActive",
+      "msg": "Game is not active"
+    },
+    {
+      "code": 6002,
+      "name": "gameNotFinished",
+      "msg": "Game is not finished"
+    },
+    {
+      "code": 6003,
+      "name": "gameFull",
+      "msg": "Game is full"
+    },
+    {
+      "code": 6004,
+      "name": "playerNotAlive",
+      "msg": "Player is not alive"
+    },
+    {
+      "code": 6005,
+      "name": "playerGameMismatch",
+      "msg": "Player does not belong to this game"
+    },
+    {
+      "code": 6006,
+      "name": "unauthorized",
+      "msg": "Unauthorized — signer does not match player authority"
+    },
+    {
+      "code": 6007,
+      "name": "invalidDirection",
+      "msg": "Invalid direction (must be 0-3)"
     },
     {
       "code": 6008,
@@ -699,216 +2262,1072 @@ export type Deadmint = {
       "msg": "Move out of bounds"
     },
     {
-      "code": 6010,
-      "name": "moveTooFast",
-      "msg": "Moving too fast — wait for cooldown"
+      "code": 6010,
+      "name": "moveTooFast",
+      "msg": "Moving too fast — wait for cooldown"
+    },
+    {
+      "code": 6011,
+      "name": "noBombsAvailable",
+      "msg": "No bombs available"
+    },
+    {
+      "code": 6012,
+      "name": "cellOccupied",
+      "msg": "Cell is occupied by a bomb"
+    },
+    {
+      "code": 6013,
+      "name": "bombSlotsFull",
+      "msg": "All bomb slots are full"
+    },
+    {
+      "code": 6014,
+      "name": "invalidBombIndex",
+      "msg": "Invalid bomb index"
+    },
+    {
+      "code": 6015,
+      "name": "bombNotActive",
+      "msg": "Bomb is not active"
+    },
+    {
+      "code": 6016,
+      "name": "bombAlreadyDetonated",
+      "msg": "Bomb already detonated"
+    },
+    {
+      "code": 6017,
+      "name": "fuseNotExpired",
+      "msg": "Fuse has not expired yet"
+    },
+    {
+      "code": 6018,
+      "name": "noWinner",
+      "msg": "No winner set"
+    },
+    {
+      "code": 6019,
+      "name": "notWinner",
+      "msg": "Not the winner"
+    },
+    {
+      "code": 6020,
+      "name": "alreadyClaimed",
+      "msg": "Prize already claimed"
+    },
+    {
+      "code": 6021,
+      "name": "mathOverflow",
+      "msg": "Math overflow"
+    },
+    {
+      "code": 6022,
+      "name": "invalidMaxPlayers",
+      "msg": "Max players must be between 2 and the number of spawn positions"
+    },
+    {
+      "code": 6023,
+      "name": "invalidGameParams",
+      "msg": "Invalid game parameters"
+    },
+    {
+      "code": 6024,
+      "name": "feeTooHigh",
+      "msg": "Platform fee exceeds the protocol cap"
+    },
+    {
+      "code": 6025,
+      "name": "invalidPayoutTable",
+      "msg": "Payout table must be non-increasing and sum to 10000 bps"
+    },
+    {
+      "code": 6026,
+      "name": "invalidBounty",
+      "msg": "Bounty exceeds the maximum bps"
+    },
+    {
+      "code": 6027,
+      "name": "lobbyNotExpired",
+      "msg": "Lobby has not expired yet — only the game authority can cancel"
+    },
+    {
+      "code": 6028,
+      "name": "invalidPlayerAccounts",
+      "msg": "Every player account and its payer wallet must be passed"
+    },
+    {
+      "code": 6029,
+      "name": "incompletePlayerSet",
+      "msg": "Every Player account of the game must be passed, in index order"
+    },
+    {
+      "code": 6030,
+      "name": "notPlaced",
+      "msg": "Player did not place in the payout table"
+    },
+    {
+      "code": 6031,
+      "name": "nothingToClaim",
+      "msg": "Nothing to claim"
+    },
+    {
+      "code": 6032,
+      "name": "gameNotSettled",
+      "msg": "Game has not been settled yet"
+    },
+    {
+      "code": 6033,
+      "name": "playersNotClosed",
+      "msg": "All player accounts must be closed first"
+    },
+    {
+      "code": 6034,
+      "name": "invalidMint",
+      "msg": "Mint does not match the game's stake mint"
+    },
+    {
+      "code": 6035,
+      "name": "missingTokenAccounts",
+      "msg": "Token accounts are required for SPL-token games"
+    },
+    {
+      "code": 6036,
+      "name": "invalidTokenAccount",
+      "msg": "Token account is not owned by the expected wallet"
+    },
+    {
+      "code": 6037,
+      "name": "missingTreasuryVault",
+      "msg": "Create the treasury vault for this mint before charging a fee in it"
+    },
+    {
+      "code": 6038,
+      "name": "protocolPaused",
+      "msg": "Protocol is paused"
+    },
+    {
+      "code": 6039,
+      "name": "invalidConfig",
+      "msg": "Invalid protocol config"
+    },
+    {
+      "code": 6040,
+      "name": "entryFeeOutOfRange",
+      "msg": "Entry fee is outside the allowed range"
+    },
+    {
+      "code": 6041,
+      "name": "insufficientFees",
+      "msg": "Withdrawal exceeds available fees"
+    },
+    {
+      "code": 6042,
+      "name": "notUpgradeAuthority",
+      "msg": "Only the program's upgrade authority can run this setup"
+    },
+    {
+      "code": 6043,
+      "name": "invalidGridSize",
+      "msg": "Grid must be odd-sized, between 9×9 and 31×21"
+    },
+    {
+      "code": 6044,
+      "name": "invalidMapOptions",
+      "msg": "Invalid map template, symmetry or densities"
+    },
+    {
+      "code": 6045,
+      "name": "invalidMapSize",
+      "msg": "Map data doesn't match the grid size or player count"
+    },
+    {
+      "code": 6046,
+      "name": "invalidMapCells",
+      "msg": "Map has an invalid cell or powerup type"
+    },
+    {
+      "code": 6047,
+      "name": "mapBorderOpen",
+      "msg": "Map border must be all walls"
+    },
+    {
+      "code": 6048,
+      "name": "spawnNotEmpty",
+      "msg": "Every spawn must be an empty cell on the grid"
+    },
+    {
+      "code": 6049,
+      "name": "duplicateSpawn",
+      "msg": "Two players can't share a spawn"
+    },
+    {
+      "code": 6050,
+      "name": "mapLocked",
+      "msg": "Map can only be changed before anyone joins"
+    },
+    {
+      "code": 6051,
+      "name": "spawnUnreachable",
+      "msg": "Spawns are walled off from each other"
+    },
+    {
+      "code": 6052,
+      "name": "spawnBoxedIn",
+      "msg": "A spawn has no open cell to escape its own bomb"
+    },
+    {
+      "code": 6053,
+      "name": "invalidSlotHashes",
+      "msg": "SlotHashes sysvar data is malformed"
+    },
+    {
+      "code": 6054,
+      "name": "invalidPlayerIndex",
+      "msg": "Invalid player index"
+    }
+  ],
+  "types": [
+    {
+      "name": "bombExploded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "bombIndex",
+            "type": "u8"
+          },
+          {
+            "name": "x",
+            "type": "u8"
+          },
+          {
+            "name": "y",
+            "type": "u8"
+          },
+          {
+            "name": "cells",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bombPlaced",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "bombIndex",
+            "type": "u8"
+          },
+          {
+            "name": "playerIndex",
+            "type": "u8"
+          },
+          {
+            "name": "x",
+            "type": "u8"
+          },
+          {
+            "name": "y",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bombSlot",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "ownerIndex",
+            "type": "u8"
+          },
+          {
+            "name": "x",
+            "type": "u8"
+          },
+          {
+            "name": "y",
+            "type": "u8"
+          },
+          {
+            "name": "range",
+            "type": "u8"
+          },
+          {
+            "name": "fuseSlots",
+            "type": "u8"
+          },
+          {
+            "name": "placedAtSlot",
+            "type": "u64"
+          },
+          {
+            "name": "detonated",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "maxPlatformFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minEntryFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxEntryFee",
+            "type": "u64"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "noBombsAvailable",
-      "msg": "No bombs available"
+      "name": "configParams",
+      "docs": [
+        "Admin-set protocol limits, validated by `initialize_config` and `update_config`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxPlatformFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minEntryFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxEntryFee",
+            "type": "u64"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "cellOccupied",
-      "msg": "Cell is occupied by a bomb"
+      "name": "game",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "gridWidth",
+            "type": "u8"
+          },
+          {
+            "name": "gridHeight",
+            "type": "u8"
+          },
+          {
+            "name": "maxPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "currentPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "entryFee",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "prizePool",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": "u8"
+          },
+          {
+            "name": "winner",
+            "type": "pubkey"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "startedAt",
+            "type": "i64"
+          },
+          {
+            "name": "roundDuration",
+            "type": "u16"
+          },
+          {
+            "name": "platformFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "bountyBps",
+            "type": "u16"
+          },
+          {
+            "name": "fuseSlots",
+            "type": "u8"
+          },
+          {
+            "name": "explosionDurationSlots",
+            "type": "u8"
+          },
+          {
+            "name": "startBombRange",
+            "type": "u8"
+          },
+          {
+            "name": "startMaxBombs",
+            "type": "u8"
+          },
+          {
+            "name": "startSpeed",
+            "type": "u8"
+          },
+          {
+            "name": "bombRangeCap",
+            "type": "u8"
+          },
+          {
+            "name": "maxBombsCap",
+            "type": "u8"
+          },
+          {
+            "name": "speedCap",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "cells",
+            "type": "bytes"
+          },
+          {
+            "name": "powerupTypes",
+            "type": "bytes"
+          },
+          {
+            "name": "bombs",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "bombSlot"
+                }
+              }
+            }
+          },
+          {
+            "name": "bombCount",
+            "type": "u8"
+          },
+          {
+            "name": "startSlot",
+            "type": "u64"
+          },
+          {
+            "name": "explosionExpiry",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "closedPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "payoutBps",
+            "type": {
+              "array": [
+                "u16",
+                3
+              ]
+            }
+          },
+          {
+            "name": "placements",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "claimedMask",
+            "type": "u8"
+          },
+          {
+            "name": "payoutPool",
+            "type": "u64"
+          },
+          {
+            "name": "platformFeeDue",
+            "type": "u64"
+          },
+          {
+            "name": "seed",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "spawns",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "spawnPoint"
+                  }
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "mapHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "bombSlotsFull",
-      "msg": "All bomb slots are full"
+      "name": "gameCancelled",
+      "docs": [
+        "An unstarted lobby was cancelled and every wager refunded."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "refunded",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "invalidBombIndex",
-      "msg": "Invalid bomb index"
+      "name": "gameClosed",
+      "docs": [
+        "A settled game's Game account (and token vault, if any) was closed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "bombNotActive",
-      "msg": "Bomb is not active"
+      "name": "gameCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "entryFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "mapHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "bombAlreadyDetonated",
-      "msg": "Bomb already detonated"
+      "name": "gameEnded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "winner",
+            "type": "pubkey"
+          },
+          {
+            "name": "placements",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "payoutPool",
+            "type": "u64"
+          },
+          {
+            "name": "platformFee",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "fuseNotExpired",
-      "msg": "Fuse has not expired yet"
+      "name": "gameParams",
+      "docs": [
+        "Creator-chosen settings for a game, validated by `initialize_game` and",
+        "copied onto the Game account, where every instruction reads them."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entryFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxPlayers",
+            "type": "u8"
+          },
+          {
+            "name": "gridWidth",
+            "type": "u8"
+          },
+          {
+            "name": "gridHeight",
+            "type": "u8"
+          },
+          {
+            "name": "roundDuration",
+            "type": "u16"
+          },
+          {
+            "name": "fuseSlots",
+            "type": "u8"
+          },
+          {
+            "name": "explosionDurationSlots",
+            "type": "u8"
+          },
+          {
+            "name": "startBombRange",
+            "type": "u8"
+          },
+          {
+            "name": "startMaxBombs",
+            "type": "u8"
+          },
+          {
+            "name": "startSpeed",
+            "type": "u8"
+          },
+          {
+            "name": "bombRangeCap",
+            "type": "u8"
+          },
+          {
+            "name": "maxBombsCap",
+            "type": "u8"
+          },
+          {
+            "name": "speedCap",
+            "type": "u8"
+          },
+          {
+            "name": "platformFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "payoutBps",
+            "type": {
+              "array": [
+                "u16",
+                3
+              ]
+            }
+          },
+          {
+            "name": "bountyBps",
+            "type": "u16"
+          },
+          {
+            "name": "map",
+            "type": {
+              "defined": {
+                "name": "mapParams"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "noWinner",
-      "msg": "No winner set"
+      "name": "gameStarted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "players",
+            "type": "u8"
+          },
+          {
+            "name": "prizePool",
+            "type": "u64"
+          },
+          {
+            "name": "startSlot",
+            "type": "u64"
+          },
+          {
+            "name": "startedAt",
+            "type": "i64"
+          },
+          {
+            "name": "seed",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "notWinner",
-      "msg": "Not the winner"
+      "name": "lootClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "alreadyClaimed",
-      "msg": "Prize already claimed"
+      "name": "lootCollected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "playerIndex",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "mathOverflow",
-      "msg": "Math overflow"
-    }
-  ],
-  "types": [
+      "name": "mapLayout",
+      "docs": [
+        "A hand-designed arena uploaded with `set_map`. `cells` and `powerup_types`",
+        "are row-major over the game's grid; `spawns` has one entry per player index."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cells",
+            "type": "bytes"
+          },
+          {
+            "name": "powerupTypes",
+            "type": "bytes"
+          },
+          {
+            "name": "spawns",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "spawnPoint"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
     {
-      "name": "bombSlot",
+      "name": "mapParams",
+      "docs": [
+        "How `initialize_game` generates the arena, seeded from the game seed.",
+        "See `deadmint_engine::map` for the templates and symmetry modes."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "active",
-            "type": "bool"
-          },
-          {
-            "name": "owner",
-            "type": "pubkey"
+            "name": "template",
+            "type": "u8"
           },
           {
-            "name": "x",
+            "name": "blockDensity",
             "type": "u8"
           },
           {
-            "name": "y",
+            "name": "lootDensity",
             "type": "u8"
           },
           {
-            "name": "range",
+            "name": "powerupDensity",
             "type": "u8"
           },
           {
-            "name": "fuseSlots",
+            "name": "symmetry",
             "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mapSet",
+      "docs": [
+        "The lobby's arena was replaced with a custom map."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
           },
           {
-            "name": "placedAtSlot",
-            "type": "u64"
+            "name": "authority",
+            "type": "pubkey"
           },
           {
-            "name": "detonated",
-            "type": "bool"
+            "name": "mapHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "game",
+      "name": "player",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "gameId",
-            "type": "u64"
+            "name": "game",
+            "type": "pubkey"
           },
           {
             "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "gridWidth",
+            "name": "playerIndex",
             "type": "u8"
           },
           {
-            "name": "gridHeight",
+            "name": "x",
             "type": "u8"
           },
           {
-            "name": "maxPlayers",
+            "name": "y",
             "type": "u8"
           },
           {
-            "name": "currentPlayers",
-            "type": "u8"
+            "name": "alive",
+            "type": "bool"
           },
           {
-            "name": "entryFee",
+            "name": "collectedSol",
             "type": "u64"
           },
           {
-            "name": "prizePool",
+            "name": "wager",
             "type": "u64"
           },
           {
-            "name": "status",
+            "name": "bombRange",
             "type": "u8"
           },
           {
-            "name": "winner",
-            "type": "pubkey"
+            "name": "maxBombs",
+            "type": "u8"
           },
           {
-            "name": "createdAt",
-            "type": "i64"
+            "name": "activeBombs",
+            "type": "u8"
           },
           {
-            "name": "startedAt",
-            "type": "i64"
+            "name": "speed",
+            "type": "u8"
           },
           {
-            "name": "roundDuration",
-            "type": "u16"
+            "name": "lastMoveSlot",
+            "type": "u64"
           },
           {
-            "name": "platformFeeBps",
-            "type": "u16"
+            "name": "kills",
+            "type": "u8"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "inputNonce",
+            "type": "u64"
           },
           {
-            "name": "cells",
-            "type": {
-              "array": [
-                "u8",
-                143
-              ]
-            }
+            "name": "eliminatedAtSlot",
+            "type": "u64"
           },
           {
-            "name": "powerupTypes",
-            "type": {
-              "array": [
-                "u8",
-                143
-              ]
-            }
+            "name": "payer",
+            "type": "pubkey"
           },
           {
-            "name": "bombs",
-            "type": {
-              "array": [
-                {
-                  "defined": {
-                    "name": "bombSlot"
-                  }
-                },
-                12
-              ]
-            }
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerClosed",
+      "docs": [
+        "A settled game's Player account was closed; `unclaimed_loot` went to `payer`",
+        "with the rent."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
           },
           {
-            "name": "bombCount",
+            "name": "playerIndex",
             "type": "u8"
           },
           {
-            "name": "lastDetonateSlot",
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "unclaimedLoot",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "player",
+      "name": "playerJoined",
       "type": {
         "kind": "struct",
         "fields": [
@@ -916,57 +3335,180 @@ export type Deadmint = {
             "name": "game",
             "type": "pubkey"
           },
+          {
+            "name": "playerIndex",
+            "type": "u8"
+          },
           {
             "name": "authority",
             "type": "pubkey"
           },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "wager",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerKilled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
           {
             "name": "playerIndex",
             "type": "u8"
           },
           {
-            "name": "x",
+            "name": "killerIndex",
+            "type": {
+              "option": "u8"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerLeft",
+      "docs": [
+        "A player left the lobby with their wager refunded. To keep indices dense,",
+        "the last joiner moved from index `moved_from` into the freed `moved_to`;",
+        "both are the leaver's index when the leaver was the last joiner."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "movedFrom",
             "type": "u8"
           },
           {
-            "name": "y",
+            "name": "movedTo",
             "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerMoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
           },
           {
-            "name": "alive",
-            "type": "bool"
+            "name": "playerIndex",
+            "type": "u8"
           },
           {
-            "name": "collectedSol",
-            "type": "u64"
+            "name": "x",
+            "type": "u8"
           },
           {
-            "name": "wager",
-            "type": "u64"
+            "name": "y",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "powerupCollected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
           },
           {
-            "name": "bombRange",
+            "name": "playerIndex",
             "type": "u8"
           },
           {
-            "name": "maxBombs",
+            "name": "powerup",
             "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prizeClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "pubkey"
           },
           {
-            "name": "activeBombs",
+            "name": "playerIndex",
             "type": "u8"
           },
           {
-            "name": "speed",
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "place",
             "type": "u8"
           },
           {
-            "name": "lastMoveSlot",
+            "name": "amount",
             "type": "u64"
           },
           {
-            "name": "kills",
+            "name": "platformFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spawnPoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "x",
+            "type": "u8"
+          },
+          {
+            "name": "y",
             "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasury",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesWithdrawn",
+            "type": "u64"
           },
           {
             "name": "bump",
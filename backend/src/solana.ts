@@ -3,6 +3,7 @@ import {
   Keypair,
   PublicKey,
   Transaction,
+  SYSVAR_SLOT_HASHES_PUBKEY,
 } from "@solana/web3.js";
 import { Program, AnchorProvider, BN } from "@coral-xyz/anchor";
 import { RPC_URL, EPHEMERAL_RPC_URL, PROGRAM_ID_STR, DELEGATION_PROGRAM_ID_STR, ER_VALIDATOR_STR, loadCrankKeypair } from "./config";
@@ -83,9 +84,13 @@ export function derivePlayerPda(gamePda: PublicKey, playerIndex: number): [Publi
   );
 }
 
-export function getAllPlayerPdas(gamePda: PublicKey, maxPlayers: number): PublicKey[] {
+export function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+export function getAllPlayerPdas(gamePda: PublicKey, count: number): PublicKey[] {
   const pdas: PublicKey[] = [];
-  for (let i = 0; i < maxPlayers; i++) {
+  for (let i = 0; i < count; i++) {
     const [pda] = derivePlayerPda(gamePda, i);
     pdas.push(pda);
   }
@@ -102,6 +107,7 @@ export interface RawGameAccount {
   maxPlayers: number;
   currentPlayers: number;
   entryFee: any;
+  mint: PublicKey;
   prizePool: any;
   status: number;
   winner: PublicKey;
@@ -109,12 +115,31 @@ export interface RawGameAccount {
   startedAt: any;
   roundDuration: number;
   platformFeeBps: number;
+  bountyBps: number;
+  fuseSlots: number;
+  explosionDurationSlots: number;
+  startBombRange: number;
+  startMaxBombs: number;
+  startSpeed: number;
+  bombRangeCap: number;
+  maxBombsCap: number;
+  speedCap: number;
   bump: number;
   cells: number[];
   powerupTypes: number[];
-  bombs: any[];
+  bombs: any[]; // one slot per possible live bomb, sized at game creation
   bombCount: number;
-  lastDetonateSlot: any;
+  startSlot: any;
+  explosionExpiry: any[];
+  closedPlayers: number;
+  payoutBps: number[];
+  placements: number[];
+  claimedMask: number;
+  payoutPool: any;
+  platformFeeDue: any;
+  seed: number[];
+  spawns: { x: number; y: number }[];
+  mapHash: number[];
 }
 
 export interface RawPlayerAccount {
@@ -133,6 +158,8 @@ export interface RawPlayerAccount {
   lastMoveSlot: any;
   kills: number;
   inputNonce: any;
+  eliminatedAtSlot: any;
+  payer: PublicKey;
   bump: number;
 }
 
@@ -211,7 +238,9 @@ export async function sendDetonateBomb(
     .detonateBomb(bombIndex)
     .accountsPartial({
       game: gamePda,
+      config: deriveConfigPda()[0],
       authority: crank.publicKey,
+      slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
     })
     .remainingAccounts(
       playerPdas.map((pk) => ({ pubkey: pk, isSigner: false, isWritable: true }))
@@ -262,7 +291,7 @@ export async function sendCheckGameEnd(
       authority: crank.publicKey,
     })
     .remainingAccounts(
-      playerPdas.map((pk) => ({ pubkey: pk, isSigner: false, isWritable: false }))
+      playerPdas.map((pk) => ({ pubkey: pk, isSigner: false, isWritable: true }))
     )
     .instruction();
 
@@ -38,7 +38,7 @@ export interface WirePlayerState {
 }
 
 export interface WireBombState {
-  owner: string | null;
+  ownerIndex: number;
   x: number;
   y: number;
   range: number;
@@ -19,8 +19,7 @@ import {
   STATUS_ACTIVE,
   STATUS_FINISHED,
   STATUS_LOBBY,
-  CANVAS_WIDTH,
-  CANVAS_HEIGHT,
+  TILE_SIZE,
 } from "@/lib/constants";
 import { deriveGamePda } from "@/lib/gameService";
 import { loadSession, saveSession, type GameSession } from "@/lib/gameSession";
@@ -136,15 +135,17 @@ export default function GamePage() {
   const containerRef = useRef<HTMLDivElement>(null);
   const [scale, setScale] = useState(1);
 
+  const gridWidth = gameState?.config.gridWidth;
+  const gridHeight = gameState?.config.gridHeight;
   const computeScale = useCallback(() => {
-    if (!containerRef.current) return;
+    if (!containerRef.current || !gridWidth || !gridHeight) return;
     const rect = containerRef.current.getBoundingClientRect();
     const availW = rect.width - 32;
     const availH = rect.height - 16;
-    const scaleX = availW / CANVAS_WIDTH;
-    const scaleY = availH / CANVAS_HEIGHT;
+    const scaleX = availW / (gridWidth * TILE_SIZE);
+    const scaleY = availH / (gridHeight * TILE_SIZE);
     setScale(Math.min(scaleX, scaleY, 2.5));
-  }, []);
+  }, [gridWidth, gridHeight]);
 
   useEffect(() => {
     computeScale();
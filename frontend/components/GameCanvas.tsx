@@ -1,7 +1,7 @@
 "use client";
 
 import { useRef, useEffect } from "react";
-import { CANVAS_WIDTH, CANVAS_HEIGHT } from "@/lib/constants";
+import { TILE_SIZE } from "@/lib/constants";
 import { initTileCache, resetTileCache, renderFrame } from "@/lib/gameRenderer";
 import { useTheme } from "@/providers/ThemeProvider";
 import type { FullGameState } from "@/lib/types";
@@ -52,8 +52,8 @@ export function GameCanvas({
     <div className="stone-border inline-block">
       <canvas
         ref={canvasRef}
-        width={CANVAS_WIDTH}
-        height={CANVAS_HEIGHT}
+        width={gameState.config.gridWidth * TILE_SIZE}
+        height={gameState.config.gridHeight * TILE_SIZE}
         className="block"
       />
     </div>
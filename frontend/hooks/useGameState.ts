@@ -215,7 +215,8 @@ export function useGameState({ mode, liveConfig }: UseGameStateOptions): UseGame
       const now = Date.now();
       const signer: gameService.Signer = liveConfig.sessionKey || liveConfig.wallet;
       const playerPdas: PublicKey[] = [];
-      for (let i = 0; i < maxPlayers; i++) {
+      // Detonation needs exactly the joined players, in index order
+      for (let i = 0; i < cachedGameConfig.currentPlayers; i++) {
         playerPdas.push(gameService.derivePlayerPda(gamePda, i)[0]);
       }
 
@@ -507,8 +508,9 @@ export function useGameState({ mode, liveConfig }: UseGameStateOptions): UseGame
 
       // Local validation: prevent sending obviously invalid TXs
       const [nx, ny] = getNewPos(localPlayer.x, localPlayer.y, direction);
-      if (nx < 0 || nx >= GRID_WIDTH || ny < 0 || ny >= GRID_HEIGHT) return;
-      const targetCell = gameState.grid.cells[ny * GRID_WIDTH + nx];
+      const { gridWidth, gridHeight } = gameState.config;
+      if (nx < 0 || nx >= gridWidth || ny < 0 || ny >= gridHeight) return;
+      const targetCell = gameState.grid.cells[ny * gridWidth + nx];
       if (!isWalkable(targetCell)) return;
 
       // Send TX to ER — no local state mutation
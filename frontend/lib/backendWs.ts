@@ -38,7 +38,7 @@ interface WireGameState {
     inputNonce: number;
   }[];
   bombs: {
-    owner: string | null;
+    ownerIndex: number;
     x: number; y: number;
     range: number;
     fuseSlots: number;
@@ -107,7 +107,7 @@ function wireToFullState(wire: WireGameState): FullGameState {
   }));
 
   const bombs: BombState[] = wire.bombs.map((b) => ({
-    owner: b.owner ? new PublicKey(b.owner) : null,
+    ownerIndex: b.ownerIndex,
     x: b.x,
     y: b.y,
     range: b.range,
@@ -19,12 +19,12 @@ export const DELEGATION_PROGRAM_ID = new PublicKey(
 );
 
 // --- Grid ---
+// Classic arena used by new games and mock mode. Live games carry their own
+// size (config.gridWidth × config.gridHeight, 9×9 up to 31×21).
 export const GRID_WIDTH = 13;
 export const GRID_HEIGHT = 11;
 export const GRID_CELLS = GRID_WIDTH * GRID_HEIGHT; // 143
 export const TILE_SIZE = 48;
-export const CANVAS_WIDTH = GRID_WIDTH * TILE_SIZE; // 624
-export const CANVAS_HEIGHT = GRID_HEIGHT * TILE_SIZE; // 528
 
 // --- Cell Types ---
 export const CELL_EMPTY = 0;
@@ -79,6 +79,10 @@ export const COLORS = {
   player1: "#3498db",
   player2: "#16c784",
   player3: "#f1c40f",
+  player4: "#9b59b6",
+  player5: "#e67e22",
+  player6: "#1abc9c",
+  player7: "#ff6b9d",
   // Tile colors
   floorDark: "#0d0d15",
   floorLight: "#12121f",
@@ -99,6 +103,10 @@ export const PLAYER_COLORS = [
   COLORS.player1,
   COLORS.player2,
   COLORS.player3,
+  COLORS.player4,
+  COLORS.player5,
+  COLORS.player6,
+  COLORS.player7,
 ];
 
-export const PLAYER_NAMES = ["Red", "Blue", "Green", "Yellow"];
+export const PLAYER_NAMES = ["Red", "Blue", "Green", "Yellow", "Purple", "Orange", "Teal", "Pink"];
@@ -1,9 +1,5 @@
 import {
   TILE_SIZE,
-  GRID_WIDTH,
-  GRID_HEIGHT,
-  CANVAS_WIDTH,
-  CANVAS_HEIGHT,
   CELL_EMPTY,
   CELL_WALL,
   CELL_BLOCK,
@@ -658,13 +654,6 @@ export function initTileCache() {
   // Compute theme-aware colors from CSS variables
   themeColors = computeThemeTileColors();
 
-  // Floor tiles (unique per position due to crack patterns)
-  for (let y = 0; y < GRID_HEIGHT; y++) {
-    for (let x = 0; x < GRID_WIDTH; x++) {
-      tileCache.set(`floor_${x}_${y}`, drawFloorTile(x, y));
-    }
-  }
-
   tileCache.set("wall", drawWallTile());
   tileCache.set("block", drawBlockTile());
 
@@ -681,29 +670,40 @@ export function initTileCache() {
   cacheInitialized = true;
 }
 
+/** Floor tiles are unique per position (crack patterns), drawn on first use since grid sizes vary. */
+function floorTile(x: number, y: number): HTMLCanvasElement {
+  const key = `floor_${x}_${y}`;
+  let tile = tileCache.get(key);
+  if (!tile) {
+    tile = drawFloorTile(x, y);
+    tileCache.set(key, tile);
+  }
+  return tile;
+}
+
 // ─── Vignette & Atmosphere ─────────────────────────────────
 
-function drawVignette(ctx: CanvasRenderingContext2D) {
+function drawVignette(ctx: CanvasRenderingContext2D, width: number, height: number) {
   const gradient = ctx.createRadialGradient(
-    CANVAS_WIDTH / 2,
-    CANVAS_HEIGHT / 2,
-    CANVAS_WIDTH * 0.3,
-    CANVAS_WIDTH / 2,
-    CANVAS_HEIGHT / 2,
-    CANVAS_WIDTH * 0.7
+    width / 2,
+    height / 2,
+    width * 0.3,
+    width / 2,
+    height / 2,
+    width * 0.7
   );
   gradient.addColorStop(0, "rgba(0,0,0,0)");
   gradient.addColorStop(1, "rgba(0,0,0,0.4)");
   ctx.fillStyle = gradient;
-  ctx.fillRect(0, 0, CANVAS_WIDTH, CANVAS_HEIGHT);
+  ctx.fillRect(0, 0, width, height);
 }
 
-function drawFog(ctx: CanvasRenderingContext2D, frame: number) {
+function drawFog(ctx: CanvasRenderingContext2D, frame: number, width: number, height: number) {
   ctx.globalAlpha = 0.03;
-  const offset = (frame * 0.2) % CANVAS_WIDTH;
+  const offset = (frame * 0.2) % width;
   ctx.fillStyle = themeColors.fogAccent;
-  ctx.fillRect(offset - CANVAS_WIDTH, 0, CANVAS_WIDTH, CANVAS_HEIGHT);
-  ctx.fillRect(offset, 0, CANVAS_WIDTH, CANVAS_HEIGHT);
+  ctx.fillRect(offset - width, 0, width, height);
+  ctx.fillRect(offset, 0, width, height);
   ctx.globalAlpha = 1;
 }
 
@@ -754,6 +754,10 @@ export function renderFrame(
 ) {
   initTileCache();
 
+  const { gridWidth, gridHeight } = state.config;
+  const canvasWidth = gridWidth * TILE_SIZE;
+  const canvasHeight = gridHeight * TILE_SIZE;
+
   // Detect new explosions for particles & screen shake, track timestamps
   const now = Date.now();
   const currentExplosions = new Set<number>();
@@ -762,8 +766,8 @@ export function renderFrame(
   });
   currentExplosions.forEach((idx) => {
     if (!prevExplosionCells.has(idx)) {
-      const cx = idx % GRID_WIDTH;
-      const cy = Math.floor(idx / GRID_WIDTH);
+      const cx = idx % gridWidth;
+      const cy = Math.floor(idx / gridWidth);
       spawnExplosionParticles(cx, cy);
       shakeFrames = 6;
       explosionTimestamps.set(idx, now);
@@ -790,13 +794,13 @@ export function renderFrame(
 
   // Clear
   ctx.fillStyle = themeColors.floorDark;
-  ctx.fillRect(-10, -10, CANVAS_WIDTH + 20, CANVAS_HEIGHT + 20);
+  ctx.fillRect(-10, -10, canvasWidth + 20, canvasHeight + 20);
 
   // Draw grid tiles
   const f = frameCount % 16;
-  for (let y = 0; y < GRID_HEIGHT; y++) {
-    for (let x = 0; x < GRID_WIDTH; x++) {
-      const idx = y * GRID_WIDTH + x;
+  for (let y = 0; y < gridHeight; y++) {
+    for (let x = 0; x < gridWidth; x++) {
+      const idx = y * gridWidth + x;
       const cell = state.grid.cells[idx];
       const dx = x * TILE_SIZE;
       const dy = y * TILE_SIZE;
@@ -804,7 +808,7 @@ export function renderFrame(
       let tile: HTMLCanvasElement | undefined;
       switch (cell) {
         case CELL_EMPTY:
-          tile = tileCache.get(`floor_${x}_${y}`);
+          tile = floorTile(x, y);
           break;
         case CELL_WALL:
           tile = tileCache.get("wall");
@@ -819,7 +823,7 @@ export function renderFrame(
           // Hide explosion tiles after visual timeout (on-chain clears lazily)
           const firstSeen = explosionTimestamps.get(idx);
           if (firstSeen && now - firstSeen > EXPLOSION_VISUAL_MS) {
-            tile = tileCache.get(`floor_${x}_${y}`);
+            tile = floorTile(x, y);
           } else {
             tile = tileCache.get(`explosion_${f}`);
           }
@@ -842,7 +846,7 @@ export function renderFrame(
   }
 
   // Fog atmosphere
-  drawFog(ctx, frameCount);
+  drawFog(ctx, frameCount, canvasWidth, canvasHeight);
 
   // Update visual lerp positions (render-only smoothing)
   updateVisualPositions(state.players, localPlayerIndex);
@@ -869,7 +873,7 @@ export function renderFrame(
   updateAndDrawParticles(ctx);
 
   // Vignette
-  drawVignette(ctx);
+  drawVignette(ctx, canvasWidth, canvasHeight);
 
   // Local player highlight (subtle glow under their character, using lerped pos)
   const lp = state.players[localPlayerIndex];
@@ -4,6 +4,7 @@ import {
   PublicKey,
   Transaction,
   SystemProgram,
+  SYSVAR_SLOT_HASHES_PUBKEY,
   type SendOptions,
 } from "@solana/web3.js";
 import { Program, AnchorProvider, BN } from "@coral-xyz/anchor";
@@ -13,6 +14,8 @@ import {
   EPHEMERAL_WS_URL,
   PROGRAM_ID,
   DELEGATION_PROGRAM_ID,
+  GRID_WIDTH,
+  GRID_HEIGHT,
 } from "./constants";
 import type { FullGameState, GameConfig, GridState, PlayerState, BombState } from "./types";
 import idlJson from "./idl/deadmint.json";
@@ -105,6 +108,19 @@ export function derivePlayerPda(gamePda: PublicKey, playerIndex: number): [Publi
   );
 }
 
+export function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+export function deriveTreasuryPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("treasury")], PROGRAM_ID);
+}
+
+/** 32 bytes of client entropy, mixed into the game seed on join. */
+function randomEntropy(): number[] {
+  return Array.from(crypto.getRandomValues(new Uint8Array(32)));
+}
+
 // ─── Send helpers ─────────────────────────────────────────────
 
 async function sendTx(
@@ -205,6 +221,29 @@ export interface CreateGameResult {
   playerPda: PublicKey;
 }
 
+/** The original fixed rules: classic 13×11 arena, no time limit, 3% fee, winner takes all, no kill bounty. */
+function defaultGameParams(entryFee: BN, maxPlayers: number) {
+  return {
+    entryFee,
+    maxPlayers,
+    gridWidth: GRID_WIDTH,
+    gridHeight: GRID_HEIGHT,
+    roundDuration: 0,
+    fuseSlots: 8,
+    explosionDurationSlots: 5,
+    startBombRange: 1,
+    startMaxBombs: 1,
+    startSpeed: 1,
+    bombRangeCap: 5,
+    maxBombsCap: 3,
+    speedCap: 3,
+    platformFeeBps: 300,
+    payoutBps: [10_000, 0, 0],
+    bountyBps: 0,
+    map: { template: 0, blockDensity: 100, lootDensity: 0, powerupDensity: 0, symmetry: 0 },
+  };
+}
+
 export async function createGameAndJoin(
   wallet: WalletAdapter,
   entryFeeLamports: BN,
@@ -218,22 +257,26 @@ export async function createGameAndJoin(
   const gameId = new BN(Date.now());
   const [gamePda] = deriveGamePda(gameId);
   const [playerPda] = derivePlayerPda(gamePda, 0);
+  const [configPda] = deriveConfigPda();
 
   const initGameIx = await program.methods
-    .initializeGame(gameId, entryFeeLamports, maxPlayers)
+    .initializeGame(gameId, defaultGameParams(entryFeeLamports, maxPlayers))
     .accountsPartial({
       game: gamePda,
+      config: configPda,
       payer,
       systemProgram: SystemProgram.programId,
     })
     .instruction();
 
   const joinGameIx = await program.methods
-    .joinGame(sessionKeyPubkey || payer)
+    .joinGame(sessionKeyPubkey || payer, randomEntropy())
     .accountsPartial({
       game: gamePda,
+      config: configPda,
       player: playerPda,
       payer,
+      slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
       systemProgram: SystemProgram.programId,
     })
     .instruction();
@@ -279,11 +322,13 @@ export async function joinGame(
   const [playerPda] = derivePlayerPda(gamePda, playerIndex);
 
   const joinIx = await program.methods
-    .joinGame(sessionKeyPubkey || payer)
+    .joinGame(sessionKeyPubkey || payer, randomEntropy())
     .accountsPartial({
       game: gamePda,
+      config: deriveConfigPda()[0],
       player: playerPda,
       payer,
+      slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
       systemProgram: SystemProgram.programId,
     })
     .instruction();
@@ -292,6 +337,73 @@ export async function joinGame(
   return { playerPda };
 }
 
+// ─── Leave Lobby ──────────────────────────────────────────────
+
+/**
+ * Leave a lobby before it starts and get the wager back. The most recently
+ * joined player is moved into the freed index, so indices stay contiguous.
+ */
+export async function leaveLobby(
+  wallet: WalletAdapter,
+  gamePda: PublicKey,
+  playerIndex: number
+): Promise<string> {
+  const connection = getBaseConnection();
+  const program = getProgram(connection);
+
+  const game = await program.account.game.fetch(gamePda);
+  const lastIndex = game.currentPlayers - 1;
+
+  const ix = await program.methods
+    .leaveLobby()
+    .accountsPartial({
+      game: gamePda,
+      player: derivePlayerPda(gamePda, playerIndex)[0],
+      lastPlayer: playerIndex === lastIndex ? null : derivePlayerPda(gamePda, lastIndex)[0],
+      payer: wallet.publicKey,
+    })
+    .instruction();
+
+  return sendTx(wallet, connection, new Transaction().add(ix));
+}
+
+// ─── Cancel Game ──────────────────────────────────────────────
+
+/**
+ * Cancel a lobby that never filled, refunding every wager to the wallet that
+ * paid it. The creator can cancel at any time, anyone else once it expires.
+ */
+export async function cancelGame(
+  wallet: WalletAdapter,
+  gamePda: PublicKey
+): Promise<string> {
+  const connection = getBaseConnection();
+  const program = getProgram(connection);
+
+  const game = await program.account.game.fetch(gamePda);
+  const refunds = [];
+  for (let i = 0; i < game.currentPlayers; i++) {
+    const [playerPda] = derivePlayerPda(gamePda, i);
+    const player = await program.account.player.fetch(playerPda);
+    refunds.push(
+      { pubkey: playerPda, isSigner: false, isWritable: true },
+      { pubkey: player.payer, isSigner: false, isWritable: true },
+    );
+  }
+
+  const ix = await program.methods
+    .cancelGame()
+    .accountsPartial({
+      game: gamePda,
+      authority: game.authority,
+      caller: wallet.publicKey,
+    })
+    .remainingAccounts(refunds)
+    .instruction();
+
+  return sendTx(wallet, connection, new Transaction().add(ix));
+}
+
 // ─── Gameplay Actions ─────────────────────────────────────────
 
 export async function movePlayer(
@@ -308,6 +420,7 @@ export async function movePlayer(
     .movePlayer(direction)
     .accountsPartial({
       game: gamePda,
+      config: deriveConfigPda()[0],
       player: playerPda,
       authority: getSignerPublicKey(signer),
     })
@@ -328,6 +441,7 @@ export async function movePlayerWithCrank(
   const connection = delegated ? getErConnection() : getBaseConnection();
   const program = getProgram(connection);
   const authority = getSignerPublicKey(signer);
+  const [configPda] = deriveConfigPda();
   const tx = new Transaction();
 
   // Add detonation IXs for expired bombs (limit to 2 for tx size)
@@ -336,7 +450,9 @@ export async function movePlayerWithCrank(
       .detonateBomb(bombIdx)
       .accountsPartial({
         game: gamePda,
+        config: configPda,
         authority,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
       })
       .remainingAccounts(
         playerPdas.map((pk) => ({ pubkey: pk, isSigner: false, isWritable: true }))
@@ -349,6 +465,7 @@ export async function movePlayerWithCrank(
     .movePlayer(direction)
     .accountsPartial({
       game: gamePda,
+      config: configPda,
       player: playerPda,
       authority,
     })
@@ -371,6 +488,7 @@ export async function placeBomb(
     .placeBomb()
     .accountsPartial({
       game: gamePda,
+      config: deriveConfigPda()[0],
       player: playerPda,
       authority: getSignerPublicKey(signer),
     })
@@ -393,7 +511,9 @@ export async function detonateBomb(
     .detonateBomb(bombIndex)
     .accountsPartial({
       game: gamePda,
+      config: deriveConfigPda()[0],
       authority: getSignerPublicKey(signer),
+      slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
     })
     .remainingAccounts(
       playerPdas.map((pk) => ({ pubkey: pk, isSigner: false, isWritable: true }))
@@ -419,7 +539,7 @@ export async function checkGameEnd(
       authority: getSignerPublicKey(signer),
     })
     .remainingAccounts(
-      playerPdas.map((pk) => ({ pubkey: pk, isSigner: false, isWritable: false }))
+      playerPdas.map((pk) => ({ pubkey: pk, isSigner: false, isWritable: true }))
     )
     .instruction();
 
@@ -434,12 +554,39 @@ export async function claimPrize(
   const connection = getBaseConnection();
   const program = getProgram(connection);
 
+  // Payouts go to the wallet that paid for the player account
+  const player = await program.account.player.fetch(playerPda);
+
   const ix = await program.methods
     .claimPrize()
     .accountsPartial({
       game: gamePda,
       player: playerPda,
-      winner: getSignerPublicKey(signer),
+      treasury: deriveTreasuryPda()[0],
+      payer: player.payer,
+    })
+    .instruction();
+
+  return sendGameplayTx(signer, connection, new Transaction().add(ix), false);
+}
+
+export async function claimLoot(
+  signer: Signer,
+  gamePda: PublicKey,
+  playerPda: PublicKey
+): Promise<string> {
+  const connection = getBaseConnection();
+  const program = getProgram(connection);
+
+  // Payouts go to the wallet that paid for the player account
+  const player = await program.account.player.fetch(playerPda);
+
+  const ix = await program.methods
+    .claimLoot()
+    .accountsPartial({
+      game: gamePda,
+      player: playerPda,
+      payer: player.payer,
     })
     .instruction();
 
@@ -528,7 +675,7 @@ async function _fetchFullGameStateFrom(
     const bombs: BombState[] = game.bombs
       .filter((b: any) => b.active || b.detonated)
       .map((b: any) => ({
-        owner: b.owner.toBase58() === PublicKey.default.toBase58() ? null : b.owner,
+        ownerIndex: b.ownerIndex,
         x: b.x, y: b.y, range: b.range,
         fuseSlots: b.fuseSlots,
         placedAtSlot: b.placedAtSlot,
@@ -604,7 +751,7 @@ export function decodeGameAccount(data: Buffer): {
       .map((b: any, i: number) => ({ ...b, _idx: i }))
       .filter((b: any) => b.active || b.detonated)
       .map((b: any) => ({
-        owner: b.owner.toBase58() === PublicKey.default.toBase58() ? null : b.owner,
+        ownerIndex: b.ownerIndex,
         x: b.x, y: b.y, range: b.range,
         fuseSlots: b.fuseSlots,
         placedAtSlot: b.placedAtSlot,
@@ -702,7 +849,7 @@ export async function fetchGameStateBatched(
       .map((b: any, i: number) => ({ ...b, _idx: i }))
       .filter((b: any) => b.active || b.detonated)
       .map((b: any) => ({
-        owner: b.owner.toBase58() === PublicKey.default.toBase58() ? null : b.owner,
+        ownerIndex: b.ownerIndex,
         x: b.x, y: b.y, range: b.range,
         fuseSlots: b.fuseSlots,
         placedAtSlot: b.placedAtSlot,
@@ -19,8 +19,8 @@ export interface GameConfig {
 }
 
 export interface GridState {
-  cells: number[]; // 143 elements, values 0-6
-  powerupTypes: number[]; // 143 elements
+  cells: number[]; // gridWidth × gridHeight elements, values 0-6
+  powerupTypes: number[]; // gridWidth × gridHeight elements
 }
 
 export interface PlayerState {
@@ -41,7 +41,7 @@ export interface PlayerState {
 }
 
 export interface BombState {
-  owner: PublicKey | null;
+  ownerIndex: number; // playerIndex of the player who placed it
   x: number;
   y: number;
   range: number;
@@ -0,0 +1,207 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::rng::Rng;
+use crate::*;
+
+/// Detonates bomb slot `bomb` (in range, checked by `step`) and every bomb its
+/// blast reaches, then resolves kills over the complete Player set.
+pub(crate) fn detonate(
+    game: &mut GameState,
+    players: &mut [PlayerState],
+    bomb: usize,
+    seed: [u8; 32],
+    now: Now,
+    events: &mut Vec<Event>,
+) -> Result<(), EngineError> {
+    let first = &game.bombs[bomb];
+    if !first.active {
+        return Err(EngineError::BombNotActive);
+    }
+    if first.detonated {
+        return Err(EngineError::BombAlreadyDetonated);
+    }
+    if now.slot < first.placed_at_slot + first.fuse_slots as u64 {
+        return Err(EngineError::FuseNotExpired);
+    }
+
+    // Clear explosions that have outlived their own duration before creating new ones
+    game.expire_explosions(now.slot);
+
+    // Detonate the bomb and every bomb its blast reaches, in chain order.
+    // Each blast keeps its owner so kills are credited to the bomb that landed them.
+    let mut rng = Rng::new(seed);
+    let mut chain = vec![bomb];
+    let mut blasts: Vec<(u8, Vec<usize>)> = Vec::with_capacity(game.bombs.len());
+    let mut next = 0;
+    while next < chain.len() {
+        let slot_idx = chain[next];
+        next += 1;
+        let Bomb { owner_index, x, y, .. } = game.bombs[slot_idx];
+        let cells = explode(game, slot_idx, now.slot, &mut rng, &mut chain);
+        events.push(Event::BombExploded {
+            bomb: slot_idx as u8,
+            x,
+            y,
+            cells: cells.iter().map(|&c| c as u16).collect(),
+        });
+        blasts.push((owner_index, cells));
+    }
+
+    // Per bomb owner index: (kills, bounty) earned in this chain
+    let mut credits: Vec<(u8, u8, u64)> = Vec::new();
+    // (victim index, killer index) in player order
+    let mut kills: Vec<(u8, Option<u8>)> = Vec::new();
+    for player in players.iter_mut() {
+        // Free one active bomb for every bomb of this player that went off
+        let freed = blasts.iter().filter(|(owner, _)| *owner == player.index).count() as u8;
+        player.active_bombs = player.active_bombs.saturating_sub(freed);
+
+        // Any alive player standing on a live explosion dies: a blast of this chain,
+        // or one still lingering from an earlier detonation. The first blast of the
+        // chain to reach them (in chain order) gets the kill; lingering deaths are
+        // not credited to anyone.
+        if !(player.alive && game.in_explosion(player.x, player.y)) {
+            continue;
+        }
+        player.eliminate(now.slot);
+
+        let player_idx = game.cell_idx(player.x, player.y);
+        let killer = blasts
+            .iter()
+            .find(|(_, cells)| cells.contains(&player_idx))
+            .map(|(owner, _)| *owner)
+            .filter(|owner| *owner != player.index);
+        kills.push((player.index, killer));
+
+        // Credit the bomb owner with the kill (self-kills don't count).
+        // In bounty mode a fraction of the victim's wager goes with it.
+        if let Some(killer) = killer {
+            let bounty = (player.wager as u128 * game.rules.bounty_bps as u128 / 10_000) as u64;
+            match credits.iter_mut().find(|(owner, _, _)| *owner == killer) {
+                Some(credit) => {
+                    credit.1 = credit.1.saturating_add(1);
+                    credit.2 = credit.2.saturating_add(bounty);
+                }
+                None => credits.push((killer, 1, bounty)),
+            }
+        }
+    }
+
+    for (player, killer) in kills {
+        events.push(Event::PlayerKilled { player, killer });
+    }
+
+    // Second pass: apply kills and bounties to each bomb owner
+    for (killer, kill_count, bounty_total) in credits {
+        let Some(owner) = players.iter_mut().find(|p| p.index == killer) else {
+            continue;
+        };
+        owner.kills = owner.kills.saturating_add(kill_count);
+
+        // Bounty moves out of the pool into the killer's collected balance
+        let bounty = bounty_total.min(game.prize_pool);
+        if bounty > 0 {
+            owner.collected = owner
+                .collected
+                .checked_add(bounty)
+                .ok_or(EngineError::MathOverflow)?;
+            game.prize_pool -= bounty;
+        }
+    }
+
+    Ok(())
+}
+
+/// Detonates one bomb slot at `slot`: frees the slot, paints its blast onto the
+/// grid and returns the blast cells. Any other live bomb the blast reaches is
+/// marked detonated and appended to `chain` so it goes off with its own range.
+/// Destroyed blocks draw their drop from `rng` in propagation order.
+fn explode(
+    game: &mut GameState,
+    slot_idx: usize,
+    slot: u64,
+    rng: &mut Rng,
+    chain: &mut Vec<usize>,
+) -> Vec<usize> {
+    let bomb = &game.bombs[slot_idx];
+    let bx = bomb.x as usize;
+    let by = bomb.y as usize;
+    let range = bomb.range as usize;
+    let width = game.grid_width as usize;
+
+    // Mark bomb as detonated
+    game.bombs[slot_idx].detonated = true;
+    game.bombs[slot_idx].active = false;
+    game.bomb_count = game.bomb_count.saturating_sub(1);
+
+    // Mark bomb cell as explosion
+    let bomb_idx = by * width + bx;
+    game.set_explosion(bomb_idx, slot);
+
+    // Collect explosion cells for player kill detection
+    let mut explosion_cells = Vec::with_capacity(1 + range * 4);
+    explosion_cells.push(bomb_idx);
+
+    // Propagate explosion in 4 directions
+    let directions: [(i16, i16); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+    for (dx, dy) in directions {
+        for dist in 1..=range {
+            let nx = bx as i16 + dx * dist as i16;
+            let ny = by as i16 + dy * dist as i16;
+
+            if nx < 0 || nx >= game.grid_width as i16 || ny < 0 || ny >= game.grid_height as i16 {
+                break;
+            }
+
+            let idx = ny as usize * width + nx as usize;
+            match game.cells[idx] {
+                CELL_WALL => {
+                    // Indestructible wall — stop this direction
+                    break;
+                }
+                CELL_BLOCK => {
+                    // Destructible block — destroy and determine loot drop
+                    let roll = rng.below(100);
+
+                    if roll < 40 {
+                        // 40% chance: SOL loot
+                        game.cells[idx] = CELL_LOOT;
+                    } else if roll < 55 {
+                        // 15% chance: powerup
+                        game.cells[idx] = CELL_POWERUP;
+                        game.powerup_types[idx] = rng.below(3) as u8 + 1;
+                    } else {
+                        // 45% chance: empty
+                        game.cells[idx] = CELL_EMPTY;
+                    }
+                    // Explosion stops at first block in this direction
+                    break;
+                }
+                CELL_BOMB => {
+                    // Another bomb — queue it for chain detonation; its own blast
+                    // carries on from there, so this direction stops here
+                    let (x, y) = (nx as u8, ny as u8);
+                    if let Some(hit) = game
+                        .bombs
+                        .iter()
+                        .position(|b| b.active && !b.detonated && b.x == x && b.y == y)
+                    {
+                        game.bombs[hit].detonated = true;
+                        chain.push(hit);
+                    }
+                    game.set_explosion(idx, slot);
+                    explosion_cells.push(idx);
+                    break;
+                }
+                _ => {
+                    // Empty, loot, powerup, or existing explosion — mark as explosion
+                    game.set_explosion(idx, slot);
+                    explosion_cells.push(idx);
+                }
+            }
+        }
+    }
+
+    explosion_cells
+}
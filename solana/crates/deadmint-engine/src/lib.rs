@@ -0,0 +1,354 @@
+//! Deadmint game rules as plain, deterministic Rust.
+//!
+//! The on-chain program converts its `Game` / `Player` accounts into a
+//! [`GameState`] and [`PlayerState`]s, applies an [`Action`] with [`step`] and
+//! writes the result back. Off-chain tools (crank, bots, replayer, tests) can
+//! run the exact same rules without a validator.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+mod blast;
+pub mod map;
+pub mod rng;
+mod state;
+
+#[cfg(test)]
+mod tests;
+
+pub use state::*;
+
+// Cell types
+pub const CELL_EMPTY: u8 = 0;
+pub const CELL_WALL: u8 = 1;
+pub const CELL_BLOCK: u8 = 2;
+pub const CELL_BOMB: u8 = 3;
+pub const CELL_EXPLOSION: u8 = 4;
+pub const CELL_LOOT: u8 = 5;
+pub const CELL_POWERUP: u8 = 6;
+
+// Powerup types (stored in `powerup_types`)
+pub const POWERUP_RANGE: u8 = 1;
+pub const POWERUP_BOMBS: u8 = 2;
+pub const POWERUP_SPEED: u8 = 3;
+
+// Game status
+pub const STATUS_LOBBY: u8 = 0;
+pub const STATUS_ACTIVE: u8 = 1;
+pub const STATUS_FINISHED: u8 = 2;
+pub const STATUS_CLAIMED: u8 = 3;
+
+// Grid dimensions are chosen per game, within these bounds (both odd, see `map`)
+pub const MIN_GRID_WIDTH: u8 = 9;
+pub const MIN_GRID_HEIGHT: u8 = 9;
+pub const MAX_GRID_WIDTH: u8 = 31;
+pub const MAX_GRID_HEIGHT: u8 = 21;
+pub const MAX_GRID_CELLS: usize = 651; // 31 × 21
+pub const MAX_PLAYERS: usize = 8;
+pub const MAX_BOMBS_PER_PLAYER: u8 = 3;
+// Upper bound on bomb slots; each game has max_players × max_bombs_cap of them
+pub const MAX_BOMBS: usize = MAX_PLAYERS * MAX_BOMBS_PER_PLAYER as usize;
+pub const MAX_SPEED: u8 = 3;
+
+/// Where and when an action happens.
+#[derive(Clone, Copy, Debug)]
+pub struct Now {
+    pub slot: u64,
+    pub unix_timestamp: i64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Move `players[player]` one cell: 0 = up, 1 = down, 2 = left, 3 = right.
+    Move { player: usize, direction: u8 },
+    /// Drop a bomb under `players[player]`.
+    PlaceBomb { player: usize },
+    /// Detonate bomb slot `bomb` and everything it chains into, rolling block
+    /// drops from `seed` (see [`rng`]). `players` must be the complete Player set.
+    Detonate { bomb: usize, seed: [u8; 32] },
+    /// End the game if at most one player is left or the round timer ran out.
+    /// `players` must be the complete Player set.
+    CheckEnd,
+}
+
+/// What happened during a `step`, in order, for logs and indexers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    PlayerMoved { player: u8, x: u8, y: u8 },
+    BombPlaced { bomb: u8, player: u8, x: u8, y: u8 },
+    /// A bomb went off; `cells` are the grid indices its blast covered.
+    BombExploded { bomb: u8, x: u8, y: u8, cells: Vec<u16> },
+    /// `killer` is the player whose bomb landed the kill, if any
+    /// (none for self-kills and lingering explosions).
+    PlayerKilled { player: u8, killer: Option<u8> },
+    LootCollected { player: u8, amount: u64 },
+    PowerupCollected { player: u8, powerup: u8 },
+    /// `winner` is all zeroes when nobody survived.
+    GameEnded { winner: Key },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineError {
+    /// The action names a player index outside `players`.
+    InvalidPlayer,
+    /// The action names a bomb slot outside `game.bombs`.
+    InvalidBomb,
+    GameNotActive,
+    PlayerNotAlive,
+    InvalidDirection,
+    CellNotWalkable,
+    OutOfBounds,
+    MoveTooFast,
+    NoBombsAvailable,
+    CellOccupied,
+    BombSlotsFull,
+    BombNotActive,
+    BombAlreadyDetonated,
+    FuseNotExpired,
+    MathOverflow,
+}
+
+/// Applies `action` to the game and returns what happened. On error nothing is
+/// meant to be persisted — the caller discards the state, as a failed
+/// transaction would.
+pub fn step(
+    game: &mut GameState,
+    players: &mut [PlayerState],
+    action: Action,
+    now: Now,
+) -> Result<Vec<Event>, EngineError> {
+    // Indices come from the caller: reject them here rather than panic on them
+    match action {
+        Action::Move { player, .. } | Action::PlaceBomb { player } if player >= players.len() => {
+            return Err(EngineError::InvalidPlayer);
+        }
+        Action::Detonate { bomb, .. } if bomb >= game.bombs.len() => return Err(EngineError::InvalidBomb),
+        _ => {}
+    }
+    if game.status != STATUS_ACTIVE {
+        return Err(EngineError::GameNotActive);
+    }
+
+    let mut events = Vec::new();
+    match action {
+        Action::Move { player, direction } => {
+            move_player(game, &mut players[player], direction, now, &mut events)?
+        }
+        Action::PlaceBomb { player } => place_bomb(game, &mut players[player], now, &mut events)?,
+        Action::Detonate { bomb, seed } => blast::detonate(game, players, bomb, seed, now, &mut events)?,
+        Action::CheckEnd => check_end(game, players, now, &mut events)?,
+    }
+    Ok(events)
+}
+
+fn move_player(
+    game: &mut GameState,
+    player: &mut PlayerState,
+    direction: u8,
+    now: Now,
+    events: &mut Vec<Event>,
+) -> Result<(), EngineError> {
+    if !player.alive {
+        return Err(EngineError::PlayerNotAlive);
+    }
+
+    // Clear explosions that have outlived their own duration
+    game.expire_explosions(now.slot);
+
+    // An explosion lingering on the player's own cell kills them before they can move
+    if game.in_explosion(player.x, player.y) {
+        player.eliminate(now.slot);
+        events.push(Event::PlayerKilled { player: player.index, killer: None });
+        return Ok(());
+    }
+
+    // Anti-spam: enforce minimum gap based on speed
+    let min_gap = 2u64.saturating_sub(player.speed.saturating_sub(1) as u64).max(1);
+    if player.last_move_slot > 0 && now.slot < player.last_move_slot + min_gap {
+        return Err(EngineError::MoveTooFast);
+    }
+
+    // Calculate new position
+    let (new_x, new_y) = match direction {
+        // Up
+        0 if player.y > 0 => (player.x, player.y - 1),
+        // Down
+        1 if player.y < game.grid_height - 1 => (player.x, player.y + 1),
+        // Left
+        2 if player.x > 0 => (player.x - 1, player.y),
+        // Right
+        3 if player.x < game.grid_width - 1 => (player.x + 1, player.y),
+        0..=3 => return Err(EngineError::OutOfBounds),
+        _ => return Err(EngineError::InvalidDirection),
+    };
+
+    let idx = game.cell_idx(new_x, new_y);
+    events.push(Event::PlayerMoved { player: player.index, x: new_x, y: new_y });
+
+    match game.cells[idx] {
+        CELL_EMPTY => {
+            // Just move
+        }
+        CELL_EXPLOSION => {
+            // Player dies
+            player.eliminate(now.slot);
+            events.push(Event::PlayerKilled { player: player.index, killer: None });
+        }
+        CELL_LOOT => {
+            // Loot pickup — value computed dynamically from prize pool.
+            // The amount is moved out of the pool into the player's loot balance,
+            // so it stays backed by the stake held by the game until claimed.
+            let loot_amount = (game.prize_pool / 50).max(1000).min(game.prize_pool);
+            player.collected = player
+                .collected
+                .checked_add(loot_amount)
+                .ok_or(EngineError::MathOverflow)?;
+            game.prize_pool -= loot_amount;
+            game.cells[idx] = CELL_EMPTY;
+            events.push(Event::LootCollected { player: player.index, amount: loot_amount });
+        }
+        CELL_POWERUP => {
+            // Powerup pickup, each stat up to the game's cap
+            let rules = game.rules;
+            let powerup = game.powerup_types[idx];
+            match powerup {
+                POWERUP_RANGE => {
+                    player.bomb_range = player.bomb_range.saturating_add(1).min(rules.bomb_range_cap);
+                }
+                POWERUP_BOMBS => {
+                    player.max_bombs = player.max_bombs.saturating_add(1).min(rules.max_bombs_cap);
+                }
+                POWERUP_SPEED => {
+                    player.speed = player.speed.saturating_add(1).min(rules.speed_cap);
+                }
+                _ => {}
+            }
+            game.powerup_types[idx] = 0;
+            game.cells[idx] = CELL_EMPTY;
+            events.push(Event::PowerupCollected { player: player.index, powerup });
+        }
+        _ => {
+            // CELL_WALL, CELL_BLOCK, CELL_BOMB — not walkable
+            return Err(EngineError::CellNotWalkable);
+        }
+    }
+
+    player.x = new_x;
+    player.y = new_y;
+    player.last_move_slot = now.slot;
+    player.input_nonce += 1;
+
+    Ok(())
+}
+
+fn place_bomb(
+    game: &mut GameState,
+    player: &mut PlayerState,
+    now: Now,
+    events: &mut Vec<Event>,
+) -> Result<(), EngineError> {
+    if !player.alive {
+        return Err(EngineError::PlayerNotAlive);
+    }
+    if player.active_bombs >= player.max_bombs {
+        return Err(EngineError::NoBombsAvailable);
+    }
+
+    // Clear explosions that have outlived their own duration
+    game.expire_explosions(now.slot);
+
+    // An explosion lingering on the player's own cell kills them before they can drop a bomb
+    if game.in_explosion(player.x, player.y) {
+        player.eliminate(now.slot);
+        events.push(Event::PlayerKilled { player: player.index, killer: None });
+        return Ok(());
+    }
+
+    let idx = game.cell_idx(player.x, player.y);
+    if !matches!(game.cells[idx], CELL_EMPTY | CELL_LOOT | CELL_POWERUP) {
+        return Err(EngineError::CellOccupied);
+    }
+
+    let slot_idx = game.find_free_bomb_slot().ok_or(EngineError::BombSlotsFull)?;
+
+    // Mark cell as bomb on the grid
+    game.cells[idx] = CELL_BOMB;
+    game.bombs[slot_idx] = Bomb {
+        active: true,
+        owner_index: player.index,
+        x: player.x,
+        y: player.y,
+        range: player.bomb_range,
+        fuse_slots: game.rules.fuse_slots,
+        placed_at_slot: now.slot,
+        detonated: false,
+    };
+    game.bomb_count += 1;
+
+    player.active_bombs += 1;
+    player.input_nonce += 1;
+    events.push(Event::BombPlaced { bomb: slot_idx as u8, player: player.index, x: player.x, y: player.y });
+
+    Ok(())
+}
+
+fn check_end(
+    game: &mut GameState,
+    players: &mut [PlayerState],
+    now: Now,
+    events: &mut Vec<Event>,
+) -> Result<(), EngineError> {
+    // Check timer expiry (round_duration == 0 means no time limit)
+    let timed_out = game.round_duration > 0
+        && game.started_at > 0
+        && (now.unix_timestamp - game.started_at) >= game.round_duration as i64;
+
+    // Clear explosions that have outlived their own duration
+    game.expire_explosions(now.slot);
+
+    let mut alive_count: u8 = 0;
+    let mut last_alive = Key::default();
+    for player in players.iter_mut() {
+        // A player standing on a live explosion dies even if they never moved
+        if player.alive && game.in_explosion(player.x, player.y) {
+            player.eliminate(now.slot);
+            events.push(Event::PlayerKilled { player: player.index, killer: None });
+        }
+
+        if player.alive {
+            alive_count += 1;
+            last_alive = player.authority;
+        }
+    }
+
+    if alive_count > 1 && !timed_out {
+        return Ok(());
+    }
+
+    game.status = STATUS_FINISHED;
+    if alive_count == 1 {
+        game.winner = last_alive;
+    }
+
+    // Rank players by how long they lasted: survivors share 1st place,
+    // then eliminated players by descending elimination slot. Players
+    // eliminated in the same slot tie and split their places' shares.
+    for player in players.iter() {
+        let place = if player.alive {
+            1
+        } else {
+            let outlasted_by = players
+                .iter()
+                .filter(|p| p.alive || p.eliminated_at_slot > player.eliminated_at_slot)
+                .count();
+            outlasted_by as u8 + 1
+        };
+        game.placements[player.index as usize] = place;
+    }
+    events.push(Event::GameEnded { winner: game.winner });
+
+    Ok(())
+}
@@ -0,0 +1,299 @@
+//! Arena layouts.
+//!
+//! [`generate`] builds a grid from a named wall template and seeded block,
+//! loot and powerup rolls (see [`rng`](crate::rng)), optionally mirrored so no
+//! spawn gets a better neighbourhood than another. [`validate`] checks that a
+//! finished grid — generated or uploaded with `set_map` — is playable: closed
+//! border, every spawn on its own empty cell, reachable from every other spawn
+//! and not boxed in.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::rng::Rng;
+use crate::*;
+
+// Wall templates
+pub const TEMPLATE_CLASSIC: u8 = 0; // pillars at every even x,y
+pub const TEMPLATE_OPEN: u8 = 1; // border walls only
+pub const TEMPLATE_STAGGERED: u8 = 2; // every other classic pillar, checkerboard style
+
+// Symmetry modes
+pub const SYMMETRY_NONE: u8 = 0;
+pub const SYMMETRY_MIRROR: u8 = 1; // left half mirrored onto the right
+pub const SYMMETRY_QUAD: u8 = 2; // top-left quarter mirrored across both axes
+
+/// How [`generate`] fills the grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MapOptions {
+    pub template: u8, // TEMPLATE_*
+    pub block_density: u8, // % of open cells outside the spawn zones that get a block
+    pub loot_density: u8, // % of the cells left open that start with loot
+    pub powerup_density: u8, // % of the cells left open that start with a powerup
+    pub symmetry: u8, // SYMMETRY_*
+}
+
+impl MapOptions {
+    /// The original fixed arena: classic pillars and a block on every other cell.
+    pub const CLASSIC: MapOptions = MapOptions {
+        template: TEMPLATE_CLASSIC,
+        block_density: 100,
+        loot_density: 0,
+        powerup_density: 0,
+        symmetry: SYMMETRY_NONE,
+    };
+
+    pub fn validate(&self) -> Result<(), MapError> {
+        let valid = self.template <= TEMPLATE_STAGGERED
+            && self.symmetry <= SYMMETRY_QUAD
+            && self.block_density <= 100
+            && self.loot_density as u16 + self.powerup_density as u16 <= 100;
+        if valid {
+            Ok(())
+        } else {
+            Err(MapError::InvalidOptions)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MapError {
+    InvalidDimensions,
+    InvalidOptions,
+    InvalidCell,
+    OpenBorder,
+    SpawnNotEmpty,
+    DuplicateSpawn,
+    SpawnUnreachable,
+    SpawnBoxedIn,
+}
+
+/// Checks a game's grid size: odd sides, so the border and the classic pillar
+/// pattern line up and every odd row and column is a corridor, between
+/// `MIN_GRID_*` and `MAX_GRID_*`.
+pub fn validate_dimensions(width: u8, height: u8) -> Result<(), MapError> {
+    let valid = (MIN_GRID_WIDTH..=MAX_GRID_WIDTH).contains(&width)
+        && (MIN_GRID_HEIGHT..=MAX_GRID_HEIGHT).contains(&height)
+        && width % 2 == 1
+        && height % 2 == 1;
+    if valid {
+        Ok(())
+    } else {
+        Err(MapError::InvalidDimensions)
+    }
+}
+
+/// Spawns for `count` players on a `width` × `height` grid: the four inner
+/// corners (top-left, top-right, bottom-left, bottom-right), then the middle of
+/// the top and bottom rows, then the middle of the left and right columns.
+/// Every spawn is on the outer corridor, and every even lobby size is
+/// left/right symmetric.
+pub fn spawn_points(width: u8, height: u8, count: usize) -> Vec<(u8, u8)> {
+    let (right, bottom) = (width - 2, height - 2);
+    let (mid_x, mid_y) = (width / 2, height / 2);
+    [
+        (1, 1),
+        (right, 1),
+        (1, bottom),
+        (right, bottom),
+        (mid_x, 1),
+        (mid_x, bottom),
+        (1, mid_y),
+        (right, mid_y),
+    ]
+    .into_iter()
+    .take(count.min(MAX_PLAYERS))
+    .collect()
+}
+
+/// Builds a `width` × `height` grid from `options` and returns its
+/// `(cells, powerup_types)`, row-major. Every roll is drawn from `seed` in
+/// row-major order, and cells near `spawns` are always left empty. With
+/// symmetry on, only the canonical part of the grid is rolled and the rest is
+/// copied from its mirror image, walls included.
+pub fn generate(
+    width: u8,
+    height: u8,
+    spawns: &[(u8, u8)],
+    options: &MapOptions,
+    seed: [u8; 32],
+) -> Result<(Vec<u8>, Vec<u8>), MapError> {
+    validate_dimensions(width, height)?;
+    options.validate()?;
+
+    let mut rng = Rng::new(seed);
+    let w = width as usize;
+    let mut cells = vec![CELL_EMPTY; w * (height as usize)];
+    let mut powerup_types = vec![0u8; cells.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let idx = (y as usize) * w + (x as usize);
+
+            // Mirrored cells copy their source, which row-major order has already filled
+            let (sx, sy) = mirror_source(x, y, width, height, options.symmetry);
+            if (sx, sy) != (x, y) {
+                let src = (sy as usize) * w + (sx as usize);
+                cells[idx] = cells[src];
+                powerup_types[idx] = powerup_types[src];
+                continue;
+            }
+
+            cells[idx] = if x == 0 || x == width - 1 || y == 0 || y == height - 1 {
+                // Border walls
+                CELL_WALL
+            } else if is_pillar(options.template, x, y) {
+                // Indestructible pillars from the template
+                CELL_WALL
+            } else if is_spawn_safe_zone(spawns, x, y) {
+                // Keep spawn zones clear
+                CELL_EMPTY
+            } else if rng.below(100) < options.block_density as u64 {
+                // Destructible block
+                CELL_BLOCK
+            } else {
+                // Open cell, possibly with a pickup already on it
+                let roll = rng.below(100);
+                if roll < options.loot_density as u64 {
+                    CELL_LOOT
+                } else if roll < options.loot_density as u64 + options.powerup_density as u64 {
+                    powerup_types[idx] = rng.below(3) as u8 + 1;
+                    CELL_POWERUP
+                } else {
+                    CELL_EMPTY
+                }
+            };
+        }
+    }
+
+    Ok((cells, powerup_types))
+}
+
+/// Keep cells clear within Manhattan distance <= 2 of each spawn.
+pub fn is_spawn_safe_zone(spawns: &[(u8, u8)], x: u8, y: u8) -> bool {
+    spawns
+        .iter()
+        .any(|&(sx, sy)| x.abs_diff(sx) + y.abs_diff(sy) <= 2)
+}
+
+/// Checks that a `width` × `height` grid is playable from `spawns`:
+/// - only empty, wall, block, loot and powerup cells, with a valid powerup type
+///   on exactly the powerup cells
+/// - the border is all walls, so no move or blast can leave the grid
+/// - every spawn is a distinct empty cell
+/// - every spawn can reach every other one once blocks are cleared
+/// - every spawn can walk, without clearing anything, at least two cells away,
+///   out of reach of a range-1 bomb dropped on the spawn
+pub fn validate(
+    cells: &[u8],
+    powerup_types: &[u8],
+    width: u8,
+    height: u8,
+    spawns: &[(u8, u8)],
+) -> Result<(), MapError> {
+    let w = width as usize;
+    let idx = |x: u8, y: u8| (y as usize) * w + (x as usize);
+
+    let total = w * (height as usize);
+    if cells.len() < total || powerup_types.len() < total {
+        return Err(MapError::InvalidCell);
+    }
+    for (&cell, &powerup) in cells[..total].iter().zip(&powerup_types[..total]) {
+        let valid = match cell {
+            CELL_EMPTY | CELL_WALL | CELL_BLOCK | CELL_LOOT => powerup == 0,
+            CELL_POWERUP => (POWERUP_RANGE..=POWERUP_SPEED).contains(&powerup),
+            _ => false,
+        };
+        if !valid {
+            return Err(MapError::InvalidCell);
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            let border = x == 0 || x == width - 1 || y == 0 || y == height - 1;
+            if border && cells[idx(x, y)] != CELL_WALL {
+                return Err(MapError::OpenBorder);
+            }
+        }
+    }
+
+    for (i, &(x, y)) in spawns.iter().enumerate() {
+        if x >= width || y >= height || cells[idx(x, y)] != CELL_EMPTY {
+            return Err(MapError::SpawnNotEmpty);
+        }
+        if spawns[..i].contains(&(x, y)) {
+            return Err(MapError::DuplicateSpawn);
+        }
+    }
+
+    // Blocks can be bombed away, so only walls separate spawns for good
+    if let Some(&(x, y)) = spawns.first() {
+        let reached = flood(cells, width, height, x, y, |cell| cell != CELL_WALL);
+        if spawns.iter().any(|&(sx, sy)| !reached[idx(sx, sy)]) {
+            return Err(MapError::SpawnUnreachable);
+        }
+    }
+
+    for &(x, y) in spawns {
+        let reached = flood(cells, width, height, x, y, |cell| {
+            matches!(cell, CELL_EMPTY | CELL_LOOT | CELL_POWERUP)
+        });
+        let has_cover = (0..height).any(|cy| {
+            (0..width).any(|cx| cx.abs_diff(x) + cy.abs_diff(y) >= 2 && reached[idx(cx, cy)])
+        });
+        if !has_cover {
+            return Err(MapError::SpawnBoxedIn);
+        }
+    }
+
+    Ok(())
+}
+
+/// Cell whose contents (x, y) copies under `symmetry`; (x, y) itself if it is canonical.
+fn mirror_source(x: u8, y: u8, width: u8, height: u8, symmetry: u8) -> (u8, u8) {
+    match symmetry {
+        SYMMETRY_MIRROR => (x.min(width - 1 - x), y),
+        SYMMETRY_QUAD => (x.min(width - 1 - x), y.min(height - 1 - y)),
+        _ => (x, y),
+    }
+}
+
+fn is_pillar(template: u8, x: u8, y: u8) -> bool {
+    // Both coordinates even
+    let classic = (x | y) & 1 == 0;
+    match template {
+        TEMPLATE_CLASSIC => classic,
+        TEMPLATE_STAGGERED => classic && (x / 2 + y / 2) & 1 == 0,
+        _ => false,
+    }
+}
+
+/// Cells reachable from (x, y) through 4-neighbours whose cell type passes `open`.
+fn flood(cells: &[u8], width: u8, height: u8, x: u8, y: u8, open: impl Fn(u8) -> bool) -> Vec<bool> {
+    let w = width as usize;
+    let h = height as usize;
+    let mut reached = vec![false; w * h];
+    let mut pending = vec![(x as usize, y as usize)];
+    reached[(y as usize) * w + (x as usize)] = true;
+
+    while let Some((cx, cy)) = pending.pop() {
+        let neighbours = [
+            (cx.wrapping_sub(1), cy),
+            (cx + 1, cy),
+            (cx, cy.wrapping_sub(1)),
+            (cx, cy + 1),
+        ];
+        for (nx, ny) in neighbours {
+            if nx >= w || ny >= h {
+                continue;
+            }
+            let n = ny * w + nx;
+            if !reached[n] && open(cells[n]) {
+                reached[n] = true;
+                pending.push((nx, ny));
+            }
+        }
+    }
+
+    reached
+}
@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use crate::*;
+
+/// A 32-byte account address (a `Pubkey` on chain).
+pub type Key = [u8; 32];
+
+/// Per-game rule settings chosen by the creator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Rules {
+    pub fuse_slots: u8,
+    pub explosion_duration_slots: u8,
+    pub bomb_range_cap: u8,
+    pub max_bombs_cap: u8,
+    pub speed_cap: u8,
+    pub bounty_bps: u16, // share of a victim's wager credited to the killer (0 = off)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Bomb {
+    pub active: bool,
+    pub owner_index: u8, // player_index of the player who placed it
+    pub x: u8,
+    pub y: u8,
+    pub range: u8,
+    pub fuse_slots: u8,
+    pub placed_at_slot: u64,
+    pub detonated: bool,
+}
+
+/// Everything about a game the rules read or change.
+#[derive(Clone, Debug)]
+pub struct GameState {
+    pub status: u8,
+    pub grid_width: u8,
+    pub grid_height: u8,
+    pub cells: Vec<u8>, // grid_width × grid_height, row-major
+    pub powerup_types: Vec<u8>,
+    pub explosion_expiry: Vec<u64>, // per cell: last live slot of a CELL_EXPLOSION, on the clock in use
+    pub bombs: Vec<Bomb>, // one slot per bomb the lobby can have out at once
+    pub bomb_count: u8,
+    pub start_slot: u64, // base-layer slot the game went active; a delegated game runs on the rollup's own slots
+    pub started_at: i64,
+    pub round_duration: u16, // seconds, 0 = no time limit
+    pub prize_pool: u64,
+    pub winner: Key, // all zeroes means no winner
+    pub placements: [u8; MAX_PLAYERS], // by player index: 1 = first, 0 = unplaced
+    pub rules: Rules,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PlayerState {
+    pub authority: Key, // session key
+    pub index: u8,
+    pub x: u8,
+    pub y: u8,
+    pub alive: bool,
+    pub collected: u64, // loot + bounty balance, already deducted from the prize pool
+    pub wager: u64,
+    pub bomb_range: u8,
+    pub max_bombs: u8,
+    pub active_bombs: u8,
+    pub speed: u8,
+    pub last_move_slot: u64,
+    pub kills: u8,
+    pub input_nonce: u64,
+    pub eliminated_at_slot: u64, // 0 while alive
+}
+
+impl GameState {
+    pub fn cell_idx(&self, x: u8, y: u8) -> usize {
+        (y as usize) * (self.grid_width as usize) + (x as usize)
+    }
+
+    pub fn find_free_bomb_slot(&self) -> Option<usize> {
+        self.bombs.iter().position(|b| !b.active)
+    }
+
+    /// Paints `idx` as an explosion that stays live for `explosion_duration_slots`
+    /// after `slot`. Overlapping a live explosion restarts that cell's timer.
+    pub fn set_explosion(&mut self, idx: usize, slot: u64) {
+        self.cells[idx] = CELL_EXPLOSION;
+        self.explosion_expiry[idx] = slot.saturating_add(self.rules.explosion_duration_slots as u64);
+    }
+
+    /// Clears every explosion cell whose own expiry has passed by `slot`.
+    pub fn expire_explosions(&mut self, slot: u64) {
+        let total = (self.grid_width as usize) * (self.grid_height as usize);
+        for i in 0..total {
+            if self.cells[i] == CELL_EXPLOSION && slot > self.explosion_expiry[i] {
+                self.cells[i] = CELL_EMPTY;
+                self.explosion_expiry[i] = 0;
+            }
+        }
+    }
+
+    /// Whether (x, y) is covered by a live explosion — lethal to any player
+    /// standing there. Callers run `expire_explosions` first.
+    pub fn in_explosion(&self, x: u8, y: u8) -> bool {
+        self.cells[self.cell_idx(x, y)] == CELL_EXPLOSION
+    }
+}
+
+impl PlayerState {
+    pub fn eliminate(&mut self, slot: u64) {
+        self.alive = false;
+        self.eliminated_at_slot = slot;
+    }
+}
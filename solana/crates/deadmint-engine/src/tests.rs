@@ -0,0 +1,431 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::*;
+
+const START_SLOT: u64 = 100;
+
+/// A `width` × `height` active game with border walls and an empty interior.
+fn open_game(width: u8, height: u8) -> GameState {
+    let total = (width as usize) * (height as usize);
+    let mut cells = vec![CELL_EMPTY; total];
+    for y in 0..height {
+        for x in 0..width {
+            if x == 0 || x == width - 1 || y == 0 || y == height - 1 {
+                cells[(y as usize) * (width as usize) + (x as usize)] = CELL_WALL;
+            }
+        }
+    }
+    GameState {
+        status: STATUS_ACTIVE,
+        grid_width: width,
+        grid_height: height,
+        cells,
+        powerup_types: vec![0; total],
+        explosion_expiry: vec![0; total],
+        bombs: vec![Bomb::default(); 8],
+        bomb_count: 0,
+        start_slot: START_SLOT,
+        started_at: 1_000,
+        round_duration: 0,
+        prize_pool: 1_000_000,
+        winner: Key::default(),
+        placements: [0; MAX_PLAYERS],
+        rules: Rules {
+            fuse_slots: 3,
+            explosion_duration_slots: 2,
+            bomb_range_cap: 5,
+            max_bombs_cap: 3,
+            speed_cap: 3,
+            bounty_bps: 0,
+        },
+    }
+}
+
+fn player(index: u8, x: u8, y: u8) -> PlayerState {
+    PlayerState {
+        authority: [index + 1; 32],
+        index,
+        x,
+        y,
+        alive: true,
+        wager: 500_000,
+        bomb_range: 1,
+        max_bombs: 1,
+        speed: 1,
+        ..PlayerState::default()
+    }
+}
+
+fn at(slot: u64) -> Now {
+    Now { slot, unix_timestamp: 1_000 + (slot - START_SLOT) as i64 }
+}
+
+fn set_cell(game: &mut GameState, x: u8, y: u8, cell: u8) {
+    let idx = game.cell_idx(x, y);
+    game.cells[idx] = cell;
+}
+
+fn cell(game: &GameState, x: u8, y: u8) -> u8 {
+    game.cells[game.cell_idx(x, y)]
+}
+
+const RIGHT: u8 = 3;
+
+#[test]
+fn move_picks_up_loot_and_powerups() {
+    let mut game = open_game(9, 9);
+    set_cell(&mut game, 2, 1, CELL_LOOT);
+    set_cell(&mut game, 3, 1, CELL_POWERUP);
+    let idx = game.cell_idx(3, 1);
+    game.powerup_types[idx] = POWERUP_RANGE;
+    let mut players = vec![player(0, 1, 1)];
+    let right = Action::Move { player: 0, direction: RIGHT };
+
+    // Loot is 2% of the pool, moved into the player's balance
+    let events = step(&mut game, &mut players, right, at(110)).unwrap();
+    assert_eq!(
+        events,
+        vec![
+            Event::PlayerMoved { player: 0, x: 2, y: 1 },
+            Event::LootCollected { player: 0, amount: 20_000 },
+        ]
+    );
+    assert_eq!(players[0].collected, 20_000);
+    assert_eq!(game.prize_pool, 980_000);
+    assert_eq!(cell(&game, 2, 1), CELL_EMPTY);
+
+    let events = step(&mut game, &mut players, right, at(112)).unwrap();
+    assert_eq!(events[1], Event::PowerupCollected { player: 0, powerup: POWERUP_RANGE });
+    assert_eq!(players[0].bomb_range, 2);
+    assert_eq!((players[0].x, players[0].y), (3, 1));
+    assert_eq!(cell(&game, 3, 1), CELL_EMPTY);
+    assert_eq!(game.powerup_types[idx], 0);
+}
+
+#[test]
+fn powerups_stop_at_the_game_caps() {
+    let mut game = open_game(9, 9);
+    set_cell(&mut game, 2, 1, CELL_POWERUP);
+    let idx = game.cell_idx(2, 1);
+    game.powerup_types[idx] = POWERUP_SPEED;
+    let mut players = vec![PlayerState { speed: 3, ..player(0, 1, 1) }];
+
+    let right = Action::Move { player: 0, direction: RIGHT };
+    step(&mut game, &mut players, right, at(110)).unwrap();
+    assert_eq!(players[0].speed, 3);
+    assert_eq!(cell(&game, 2, 1), CELL_EMPTY);
+}
+
+#[test]
+fn move_gap_shrinks_with_speed() {
+    let mut game = open_game(9, 9);
+    let mut players = vec![player(0, 1, 1)];
+    let right = Action::Move { player: 0, direction: RIGHT };
+
+    // Speed 1: two slots between moves
+    step(&mut game, &mut players, right, at(110)).unwrap();
+    assert_eq!(step(&mut game, &mut players, right, at(111)), Err(EngineError::MoveTooFast));
+    step(&mut game, &mut players, right, at(112)).unwrap();
+
+    // Speed 2 and up: one slot
+    players[0].speed = 2;
+    step(&mut game, &mut players, right, at(113)).unwrap();
+    assert_eq!(players[0].x, 4);
+    assert_eq!(step(&mut game, &mut players, right, at(113)), Err(EngineError::MoveTooFast));
+}
+
+#[test]
+fn out_of_range_indices_are_errors_not_panics() {
+    let mut game = open_game(9, 9);
+    let mut players = vec![player(0, 1, 1)];
+
+    let moved = Action::Move { player: 1, direction: RIGHT };
+    assert_eq!(step(&mut game, &mut players, moved, at(110)), Err(EngineError::InvalidPlayer));
+    let placed = Action::PlaceBomb { player: 5 };
+    assert_eq!(step(&mut game, &mut players, placed, at(110)), Err(EngineError::InvalidPlayer));
+    let detonated = Action::Detonate { bomb: game.bombs.len(), seed: [0; 32] };
+    assert_eq!(step(&mut game, &mut players, detonated, at(110)), Err(EngineError::InvalidBomb));
+}
+
+#[test]
+fn kill_and_bounty_follow_the_bomb_owner_index_not_the_signer() {
+    let mut game = open_game(9, 9);
+    game.rules.bounty_bps = 5_000;
+    // Both players act through the same session key; A's bomb at (3,3) kills B at (4,3)
+    let shared = [9; 32];
+    let mut players = vec![
+        PlayerState { authority: shared, ..player(0, 3, 3) },
+        PlayerState { authority: shared, ..player(1, 4, 3) },
+    ];
+    step(&mut game, &mut players, Action::PlaceBomb { player: 0 }, at(110)).unwrap();
+    assert_eq!(game.bombs[0].owner_index, 0);
+    (players[0].x, players[0].y) = (1, 7);
+
+    let detonate = Action::Detonate { bomb: 0, seed: [0; 32] };
+    let events = step(&mut game, &mut players, detonate, at(113)).unwrap();
+
+    assert_eq!(events.last(), Some(&Event::PlayerKilled { player: 1, killer: Some(0) }));
+    assert_eq!((players[0].kills, players[1].kills), (1, 0));
+    assert_eq!((players[0].active_bombs, players[1].active_bombs), (0, 0));
+    assert_eq!(players[0].collected, 250_000);
+    assert_eq!(game.prize_pool, 750_000);
+}
+
+#[test]
+fn explosions_expire_cell_by_cell() {
+    let mut game = open_game(9, 9);
+    let (older, newer) = (game.cell_idx(2, 1), game.cell_idx(3, 1));
+    game.set_explosion(older, 110);
+    game.set_explosion(newer, 111);
+
+    // Each cell stays live for explosion_duration_slots after its own blast
+    game.expire_explosions(112);
+    assert!(game.in_explosion(2, 1) && game.in_explosion(3, 1));
+    game.expire_explosions(113);
+    assert!(!game.in_explosion(2, 1));
+    assert_eq!(game.explosion_expiry[older], 0);
+    assert!(game.in_explosion(3, 1));
+    game.expire_explosions(114);
+    assert!(!game.in_explosion(3, 1));
+}
+
+#[test]
+fn explosions_expire_on_rollup_slots_behind_the_start_slot() {
+    // Started at a base-layer slot far ahead of the rollup clock the actions run on
+    let mut game = GameState { start_slot: 50_000, ..open_game(9, 9) };
+    let mut players = vec![player(0, 3, 3), player(1, 7, 7)];
+    step(&mut game, &mut players, Action::PlaceBomb { player: 0 }, at(110)).unwrap();
+    (players[0].x, players[0].y) = (1, 7);
+
+    step(&mut game, &mut players, Action::Detonate { bomb: 0, seed: [0; 32] }, at(113)).unwrap();
+    assert_eq!(game.explosion_expiry[game.cell_idx(3, 3)], 115);
+
+    game.expire_explosions(115);
+    assert!(game.in_explosion(3, 3));
+    game.expire_explosions(116);
+    assert!(!game.cells.contains(&CELL_EXPLOSION));
+}
+
+#[test]
+fn check_end_kills_a_player_standing_in_a_live_explosion() {
+    let mut game = open_game(9, 9);
+    let mut players = vec![player(0, 1, 1), player(1, 7, 1), player(2, 4, 4)];
+    // A lingering blast under C, who never moves again
+    let idx = game.cell_idx(4, 4);
+    game.set_explosion(idx, 110);
+
+    let events = step(&mut game, &mut players, Action::CheckEnd, at(111)).unwrap();
+
+    assert!(!players[2].alive);
+    assert_eq!(players[2].eliminated_at_slot, 111);
+    assert_eq!(events, vec![Event::PlayerKilled { player: 2, killer: None }]);
+    assert!(players[0].alive && players[1].alive);
+    assert_eq!(game.status, STATUS_ACTIVE);
+}
+
+#[test]
+fn placements_rank_by_elimination_and_tie_same_slot_deaths() {
+    let mut game = open_game(9, 9);
+    let mut players = vec![player(0, 1, 1), player(1, 7, 1), player(2, 1, 7), player(3, 7, 7)];
+    players[1].eliminate(115);
+    players[2].eliminate(120);
+    players[3].eliminate(120);
+
+    let events = step(&mut game, &mut players, Action::CheckEnd, at(121)).unwrap();
+
+    assert_eq!(game.status, STATUS_FINISHED);
+    assert_eq!(game.winner, players[0].authority);
+    assert_eq!(game.placements[..4], [1, 4, 2, 2]);
+    assert_eq!(events, vec![Event::GameEnded { winner: players[0].authority }]);
+}
+
+#[test]
+fn timed_out_survivors_share_first_place() {
+    let mut game = GameState { round_duration: 30, ..open_game(9, 9) };
+    let mut players: Vec<PlayerState> = (0..3).map(|i| player(i, 1 + 2 * i, 1)).collect();
+    players[2].eliminate(110);
+
+    // Still running before the timer
+    step(&mut game, &mut players, Action::CheckEnd, at(129)).unwrap();
+    assert_eq!(game.status, STATUS_ACTIVE);
+
+    step(&mut game, &mut players, Action::CheckEnd, at(130)).unwrap();
+    assert_eq!(game.status, STATUS_FINISHED);
+    assert_eq!(game.winner, Key::default());
+    assert_eq!(game.placements[..3], [1, 1, 3]);
+}
+
+#[test]
+fn chain_reaction_frees_every_bomb_and_credits_each_kill_to_its_bomb() {
+    let mut game = open_game(9, 9);
+    // A's bomb at (3,3) kills D at (3,2) and sets off B's bomb at (4,3),
+    // whose range-2 blast kills C at (6,3)
+    let mut players = vec![
+        player(0, 3, 3),
+        PlayerState { bomb_range: 2, ..player(1, 4, 3) },
+        player(2, 6, 3),
+        player(3, 3, 2),
+    ];
+    step(&mut game, &mut players, Action::PlaceBomb { player: 0 }, at(110)).unwrap();
+    step(&mut game, &mut players, Action::PlaceBomb { player: 1 }, at(110)).unwrap();
+    assert_eq!((players[0].active_bombs, players[1].active_bombs), (1, 1));
+    (players[0].x, players[0].y) = (1, 7);
+    (players[1].x, players[1].y) = (7, 7);
+
+    let detonate = Action::Detonate { bomb: 0, seed: [0; 32] };
+    let events = step(&mut game, &mut players, detonate, at(113)).unwrap();
+
+    // Both bombs went off in this one step, in chain order
+    let exploded: Vec<u8> = events
+        .iter()
+        .filter_map(|e| match e {
+            Event::BombExploded { bomb, .. } => Some(*bomb),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(exploded, vec![0, 1]);
+    assert_eq!((players[0].active_bombs, players[1].active_bombs), (0, 0));
+    assert_eq!(game.find_free_bomb_slot(), Some(0));
+
+    let kills: Vec<&Event> = events.iter().filter(|e| matches!(e, Event::PlayerKilled { .. })).collect();
+    assert_eq!(
+        kills,
+        vec![
+            &Event::PlayerKilled { player: 2, killer: Some(1) },
+            &Event::PlayerKilled { player: 3, killer: Some(0) },
+        ]
+    );
+    assert_eq!((players[0].kills, players[1].kills), (1, 1));
+    assert!(players[0].alive && players[1].alive);
+}
+
+#[test]
+fn overlapping_blast_outlives_the_older_explosion_around_it() {
+    let mut game = open_game(9, 9);
+    // A's bomb at (3,3) and B's at (5,3) both reach (4,3); Q and R wait outside both blasts
+    let mut players = vec![player(0, 3, 3), player(1, 5, 3), player(2, 4, 4), player(3, 2, 2)];
+    step(&mut game, &mut players, Action::PlaceBomb { player: 0 }, at(110)).unwrap();
+    step(&mut game, &mut players, Action::PlaceBomb { player: 1 }, at(112)).unwrap();
+    (players[0].x, players[0].y) = (1, 7);
+    (players[1].x, players[1].y) = (7, 7);
+
+    // A's blast is live until slot 115, B's (which restarts (4,3)) until 117
+    step(&mut game, &mut players, Action::Detonate { bomb: 0, seed: [0; 32] }, at(113)).unwrap();
+    step(&mut game, &mut players, Action::Detonate { bomb: 1, seed: [0; 32] }, at(115)).unwrap();
+    assert!(game.in_explosion(3, 3) && game.in_explosion(4, 3) && game.in_explosion(5, 3));
+
+    // At 116 A's cells have cleared on their own timer, the overlap has not
+    game.expire_explosions(116);
+    assert_eq!(cell(&game, 3, 3), CELL_EMPTY);
+    assert_eq!(cell(&game, 2, 3), CELL_EMPTY);
+    assert!(game.in_explosion(4, 3) && game.in_explosion(5, 3));
+
+    // So R can walk into A's old blast, while Q dies stepping into the overlap
+    step(&mut game, &mut players, Action::Move { player: 3, direction: 1 }, at(116)).unwrap();
+    assert!(players[3].alive);
+    let events = step(&mut game, &mut players, Action::Move { player: 2, direction: 0 }, at(116)).unwrap();
+    assert_eq!(events[1], Event::PlayerKilled { player: 2, killer: None });
+
+    game.expire_explosions(118);
+    assert!(!game.cells.contains(&CELL_EXPLOSION));
+}
+
+fn corner_spawns() -> Vec<(u8, u8)> {
+    map::spawn_points(13, 11, 4)
+}
+
+#[test]
+fn map_generation_is_deterministic_per_seed() {
+    let options = map::MapOptions {
+        block_density: 50,
+        loot_density: 20,
+        powerup_density: 20,
+        ..map::MapOptions::CLASSIC
+    };
+    let spawns = corner_spawns();
+
+    let first = map::generate(13, 11, &spawns, &options, [7; 32]).unwrap();
+    assert_eq!(map::generate(13, 11, &spawns, &options, [7; 32]).unwrap(), first);
+    assert_ne!(map::generate(13, 11, &spawns, &options, [8; 32]).unwrap(), first);
+}
+
+#[test]
+fn mirror_and_quad_symmetry_copy_cells_across_the_axes() {
+    let (w, h) = (13usize, 11usize);
+    let spawns = corner_spawns();
+    let options = map::MapOptions {
+        template: map::TEMPLATE_STAGGERED,
+        block_density: 50,
+        loot_density: 20,
+        powerup_density: 20,
+        symmetry: map::SYMMETRY_MIRROR,
+    };
+
+    let (cells, powerups) = map::generate(13, 11, &spawns, &options, [3; 32]).unwrap();
+    for y in 0..h {
+        for x in 0..w {
+            let (a, b) = (y * w + x, y * w + (w - 1 - x));
+            assert_eq!((cells[a], powerups[a]), (cells[b], powerups[b]));
+        }
+    }
+
+    let quad = map::MapOptions { symmetry: map::SYMMETRY_QUAD, ..options };
+    let (cells, powerups) = map::generate(13, 11, &spawns, &quad, [3; 32]).unwrap();
+    for y in 0..h {
+        for x in 0..w {
+            let a = y * w + x;
+            for b in [y * w + (w - 1 - x), (h - 1 - y) * w + x, (h - 1 - y) * w + (w - 1 - x)] {
+                assert_eq!((cells[a], powerups[a]), (cells[b], powerups[b]));
+            }
+        }
+    }
+}
+
+#[test]
+fn map_validation_rejects_unplayable_grids() {
+    let spawns = corner_spawns();
+    let (cells, powerups) = map::generate(13, 11, &spawns, &map::MapOptions::CLASSIC, [0; 32]).unwrap();
+    assert_eq!(map::validate(&cells, &powerups, 13, 11, &spawns), Ok(()));
+    let idx = |x: usize, y: usize| y * 13 + x;
+    let check = |cells: &[u8], spawns: &[(u8, u8)]| map::validate(cells, &powerups, 13, 11, spawns);
+
+    // A gap in the border wall
+    let mut open = cells.clone();
+    open[idx(0, 5)] = CELL_EMPTY;
+    assert_eq!(check(&open, &spawns), Err(map::MapError::OpenBorder));
+
+    // A spawn sitting on a block
+    let mut covered = cells.clone();
+    covered[idx(1, 1)] = CELL_BLOCK;
+    assert_eq!(check(&covered, &spawns), Err(map::MapError::SpawnNotEmpty));
+
+    // Two players on the same cell
+    assert_eq!(check(&cells, &[(1, 1), (1, 1)]), Err(map::MapError::DuplicateSpawn));
+
+    // A wall column cutting the left spawns off from the right ones
+    let mut split = cells.clone();
+    for y in 1..10 {
+        split[idx(6, y)] = CELL_WALL;
+    }
+    assert_eq!(check(&split, &spawns), Err(map::MapError::SpawnUnreachable));
+
+    // Blocks right next to a spawn leave it nowhere to hide from its own bomb
+    let mut boxed = cells.clone();
+    boxed[idx(2, 1)] = CELL_BLOCK;
+    boxed[idx(1, 2)] = CELL_BLOCK;
+    assert_eq!(check(&boxed, &spawns), Err(map::MapError::SpawnBoxedIn));
+}
+
+#[test]
+fn pickup_densities_above_100_percent_are_invalid() {
+    let options = map::MapOptions { loot_density: 60, powerup_density: 41, ..map::MapOptions::CLASSIC };
+    assert_eq!(options.validate(), Err(map::MapError::InvalidOptions));
+    assert_eq!(
+        map::generate(13, 11, &corner_spawns(), &options, [0; 32]),
+        Err(map::MapError::InvalidOptions)
+    );
+
+    let full = map::MapOptions { loot_density: 60, powerup_density: 40, ..map::MapOptions::CLASSIC };
+    assert_eq!(full.validate(), Ok(()));
+}
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use deadmint_engine::map::MapError;
+use deadmint_engine::EngineError;
 
 #[error_code]
 pub enum DeadmintError {
@@ -47,6 +49,8 @@ pub enum DeadmintError {
     FuseNotExpired,
 
     // Claim errors
+    // NoWinner and NotWinner are no longer returned (payouts follow the
+    // placement table); they stay so the codes after them don't shift.
     #[msg("No winner set")]
     NoWinner,
     #[msg("Not the winner")]
@@ -57,4 +61,126 @@ pub enum DeadmintError {
     // Math
     #[msg("Math overflow")]
     MathOverflow,
+
+    // Clients match on error codes, so new variants go at the end of the
+    // enum: inserting one above shifts every code after it.
+
+    // Game setup errors
+    #[msg("Max players must be between 2 and the number of spawn positions")]
+    InvalidMaxPlayers,
+    #[msg("Invalid game parameters")]
+    InvalidGameParams,
+    #[msg("Platform fee exceeds the protocol cap")]
+    FeeTooHigh,
+    #[msg("Payout table must be non-increasing and sum to 10000 bps")]
+    InvalidPayoutTable,
+    #[msg("Bounty exceeds the maximum bps")]
+    InvalidBounty,
+    #[msg("Lobby has not expired yet — only the game authority can cancel")]
+    LobbyNotExpired,
+    #[msg("Every player account and its payer wallet must be passed")]
+    InvalidPlayerAccounts,
+    #[msg("Every Player account of the game must be passed, in index order")]
+    IncompletePlayerSet,
+
+    // Settlement errors
+    #[msg("Player did not place in the payout table")]
+    NotPlaced,
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    #[msg("Game has not been settled yet")]
+    GameNotSettled,
+    #[msg("All player accounts must be closed first")]
+    PlayersNotClosed,
+
+    // Token errors
+    #[msg("Mint does not match the game's stake mint")]
+    InvalidMint,
+    #[msg("Token accounts are required for SPL-token games")]
+    MissingTokenAccounts,
+    #[msg("Token account is not owned by the expected wallet")]
+    InvalidTokenAccount,
+    #[msg("Create the treasury vault for this mint before charging a fee in it")]
+    MissingTreasuryVault,
+
+    // Config errors
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+    #[msg("Invalid protocol config")]
+    InvalidConfig,
+    #[msg("Entry fee is outside the allowed range")]
+    EntryFeeOutOfRange,
+
+    // Treasury errors
+    #[msg("Withdrawal exceeds available fees")]
+    InsufficientFees,
+    #[msg("Only the program's upgrade authority can run this setup")]
+    NotUpgradeAuthority,
+
+    // Map errors
+    #[msg("Grid must be odd-sized, between 9×9 and 31×21")]
+    InvalidGridSize,
+    #[msg("Invalid map template, symmetry or densities")]
+    InvalidMapOptions,
+    #[msg("Map data doesn't match the grid size or player count")]
+    InvalidMapSize,
+    #[msg("Map has an invalid cell or powerup type")]
+    InvalidMapCells,
+    #[msg("Map border must be all walls")]
+    MapBorderOpen,
+    #[msg("Every spawn must be an empty cell on the grid")]
+    SpawnNotEmpty,
+    #[msg("Two players can't share a spawn")]
+    DuplicateSpawn,
+    #[msg("Map can only be changed before anyone joins")]
+    MapLocked,
+    #[msg("Spawns are walled off from each other")]
+    SpawnUnreachable,
+    #[msg("A spawn has no open cell to escape its own bomb")]
+    SpawnBoxedIn,
+
+    // Randomness errors
+    #[msg("SlotHashes sysvar data is malformed")]
+    InvalidSlotHashes,
+
+    // Engine errors
+    #[msg("Invalid player index")]
+    InvalidPlayerIndex,
+}
+
+impl From<EngineError> for DeadmintError {
+    fn from(err: EngineError) -> Self {
+        match err {
+            EngineError::InvalidPlayer => DeadmintError::InvalidPlayerIndex,
+            EngineError::InvalidBomb => DeadmintError::InvalidBombIndex,
+            EngineError::GameNotActive => DeadmintError::GameNotActive,
+            EngineError::PlayerNotAlive => DeadmintError::PlayerNotAlive,
+            EngineError::InvalidDirection => DeadmintError::InvalidDirection,
+            EngineError::CellNotWalkable => DeadmintError::CellNotWalkable,
+            EngineError::OutOfBounds => DeadmintError::OutOfBounds,
+            EngineError::MoveTooFast => DeadmintError::MoveTooFast,
+            EngineError::NoBombsAvailable => DeadmintError::NoBombsAvailable,
+            EngineError::CellOccupied => DeadmintError::CellOccupied,
+            EngineError::BombSlotsFull => DeadmintError::BombSlotsFull,
+            EngineError::BombNotActive => DeadmintError::BombNotActive,
+            EngineError::BombAlreadyDetonated => DeadmintError::BombAlreadyDetonated,
+            EngineError::FuseNotExpired => DeadmintError::FuseNotExpired,
+            EngineError::MathOverflow => DeadmintError::MathOverflow,
+        }
+    }
+}
+
+impl From<MapError> for DeadmintError {
+    fn from(err: MapError) -> Self {
+        match err {
+            MapError::InvalidDimensions => DeadmintError::InvalidGridSize,
+            MapError::InvalidOptions => DeadmintError::InvalidMapOptions,
+            MapError::InvalidCell => DeadmintError::InvalidMapCells,
+            MapError::OpenBorder => DeadmintError::MapBorderOpen,
+            MapError::SpawnNotEmpty => DeadmintError::SpawnNotEmpty,
+            MapError::DuplicateSpawn => DeadmintError::DuplicateSpawn,
+            MapError::SpawnUnreachable => DeadmintError::SpawnUnreachable,
+            MapError::SpawnBoxedIn => DeadmintError::SpawnBoxedIn,
+        }
+    }
 }
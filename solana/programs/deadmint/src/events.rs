@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use deadmint_engine::Event;
+use crate::state::*;
+
+// Typed events for every game state transition, so indexers and clients can
+// follow a game from the program logs instead of diffing Game accounts.
+// Players are identified by their `player_index` within the game.
+
+#[event]
+pub struct GameCreated {
+    pub game: Pubkey,
+    pub game_id: u64,
+    pub authority: Pubkey,
+    pub mint: Pubkey, // Pubkey::default() for native SOL
+    pub entry_fee: u64,
+    pub max_players: u8,
+    pub map_hash: [u8; 32],
+}
+
+/// The lobby's arena was replaced with a custom map.
+#[event]
+pub struct MapSet {
+    pub game: Pubkey,
+    pub authority: Pubkey,
+    pub map_hash: [u8; 32],
+}
+
+#[event]
+pub struct PlayerJoined {
+    pub game: Pubkey,
+    pub player_index: u8,
+    pub authority: Pubkey, // session key
+    pub payer: Pubkey,
+    pub wager: u64,
+}
+
+/// A player left the lobby with their wager refunded. To keep indices dense,
+/// the last joiner moved from index `moved_from` into the freed `moved_to`;
+/// both are the leaver's index when the leaver was the last joiner.
+#[event]
+pub struct PlayerLeft {
+    pub game: Pubkey,
+    pub player: Pubkey, // wallet that paid the wager and got it back
+    pub moved_from: u8,
+    pub moved_to: u8,
+}
+
+/// An unstarted lobby was cancelled and every wager refunded.
+#[event]
+pub struct GameCancelled {
+    pub game: Pubkey,
+    pub refunded: u8, // players refunded
+}
+
+#[event]
+pub struct GameStarted {
+    pub game: Pubkey,
+    pub players: u8,
+    pub prize_pool: u64,
+    pub start_slot: u64,
+    pub started_at: i64,
+    pub seed: [u8; 32], // final randomness seed, for auditing drops
+}
+
+#[event]
+pub struct PlayerMoved {
+    pub game: Pubkey,
+    pub player_index: u8,
+    pub x: u8,
+    pub y: u8,
+}
+
+#[event]
+pub struct BombPlaced {
+    pub game: Pubkey,
+    pub bomb_index: u8,
+    pub player_index: u8,
+    pub x: u8,
+    pub y: u8,
+}
+
+#[event]
+pub struct BombExploded {
+    pub game: Pubkey,
+    pub bomb_index: u8,
+    pub x: u8,
+    pub y: u8,
+    pub cells: Vec<u16>, // grid indices covered by the blast
+}
+
+#[event]
+pub struct PlayerKilled {
+    pub game: Pubkey,
+    pub player_index: u8,
+    pub killer_index: Option<u8>, // None for self-kills and lingering explosions
+}
+
+#[event]
+pub struct LootCollected {
+    pub game: Pubkey,
+    pub player_index: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PowerupCollected {
+    pub game: Pubkey,
+    pub player_index: u8,
+    pub powerup: u8, // 1 = range, 2 = extra bomb, 3 = speed
+}
+
+#[event]
+pub struct GameEnded {
+    pub game: Pubkey,
+    pub winner: Pubkey, // Pubkey::default() when nobody survived
+    pub placements: [u8; MAX_PLAYERS],
+    pub payout_pool: u64,
+    pub platform_fee: u64,
+}
+
+#[event]
+pub struct PrizeClaimed {
+    pub game: Pubkey,
+    pub player_index: u8,
+    pub payer: Pubkey,
+    pub place: u8,
+    pub amount: u64,
+    pub platform_fee: u64, // fee sent to the treasury with this claim (first claim only)
+}
+
+#[event]
+pub struct LootClaimed {
+    pub game: Pubkey,
+    pub player: Pubkey, // wallet that paid the wager and receives the loot
+    pub amount: u64,
+}
+
+/// A settled game's Player account was closed; `unclaimed_loot` went to `payer`
+/// with the rent.
+#[event]
+pub struct PlayerClosed {
+    pub game: Pubkey,
+    pub player_index: u8,
+    pub payer: Pubkey,
+    pub unclaimed_loot: u64,
+}
+
+/// A settled game's Game account (and token vault, if any) was closed.
+#[event]
+pub struct GameClosed {
+    pub game: Pubkey,
+    pub authority: Pubkey, // creator, who got the rent back
+}
+
+/// Emits the events the engine reported for one `step`, once `game` holds its result.
+pub fn emit_engine_events(game_key: Pubkey, game: &Game, events: Vec<Event>) {
+    for event in events {
+        match event {
+            Event::PlayerMoved { player, x, y } => emit!(PlayerMoved {
+                game: game_key,
+                player_index: player,
+                x,
+                y,
+            }),
+            Event::BombPlaced { bomb, player, x, y } => emit!(BombPlaced {
+                game: game_key,
+                bomb_index: bomb,
+                player_index: player,
+                x,
+                y,
+            }),
+            Event::BombExploded { bomb, x, y, cells } => emit!(BombExploded {
+                game: game_key,
+                bomb_index: bomb,
+                x,
+                y,
+                cells,
+            }),
+            Event::PlayerKilled { player, killer } => emit!(PlayerKilled {
+                game: game_key,
+                player_index: player,
+                killer_index: killer,
+            }),
+            Event::LootCollected { player, amount } => emit!(LootCollected {
+                game: game_key,
+                player_index: player,
+                amount,
+            }),
+            Event::PowerupCollected { player, powerup } => emit!(PowerupCollected {
+                game: game_key,
+                player_index: player,
+                powerup,
+            }),
+            Event::GameEnded { winner } => emit!(GameEnded {
+                game: game_key,
+                winner: Pubkey::new_from_array(winner),
+                placements: game.placements,
+                payout_pool: game.payout_pool,
+                platform_fee: game.platform_fee_due,
+            }),
+        }
+    }
+}
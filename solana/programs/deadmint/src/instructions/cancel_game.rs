@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::DeadmintError;
+use crate::events::GameCancelled;
+use crate::stake;
+
+#[derive(Accounts)]
+pub struct CancelGame<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.game_id.to_le_bytes().as_ref()],
+        bump = game.bump,
+        close = authority,
+    )]
+    pub game: Account<'info, Game>,
+    /// CHECK: Game creator — receives the Game account rent
+    #[account(mut, address = game.authority @ DeadmintError::Unauthorized)]
+    pub authority: UncheckedAccount<'info>,
+    /// The game authority at any time, or anyone once the lobby has expired
+    pub caller: Signer<'info>,
+    // Token accounts — only for SPL-token games (the vault is closed too)
+    #[account(address = game.mint @ DeadmintError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"vault", game.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Creator's token account for any leftover vault balance
+    #[account(mut)]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    // remaining_accounts, for every player index in order:
+    // [player_pda, payer_wallet] for SOL games,
+    // [player_pda, payer_wallet, payer_token_account] for SPL-token games
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CancelGame<'info>>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+
+    require!(game.status == STATUS_LOBBY, DeadmintError::GameNotInLobby);
+
+    if ctx.accounts.caller.key() != game.authority {
+        let clock = Clock::get()?;
+        require!(
+            clock.unix_timestamp >= game.created_at + LOBBY_EXPIRY_SECONDS,
+            DeadmintError::LobbyNotExpired
+        );
+    }
+
+    let token_stake = stake::token_stake(
+        game,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    let stride = if token_stake.is_some() { 3 } else { 2 };
+
+    // Every joined player must be refunded, otherwise their wager would be
+    // swept to the authority when the Game account closes.
+    require!(
+        ctx.remaining_accounts.len() == game.current_players as usize * stride,
+        DeadmintError::InvalidPlayerAccounts
+    );
+
+    let game_key = game.key();
+
+    for (i, group) in ctx.remaining_accounts.chunks(stride).enumerate() {
+        let player_info = &group[0];
+        let payer_info = &group[1];
+        let payer_token_account = match group.get(2) {
+            Some(info) => Some(InterfaceAccount::<'info, TokenAccount>::try_from(info)?),
+            None => None,
+        };
+
+        // Deserialization checks owner + discriminator
+        let player: Account<'info, Player> = Account::try_from(player_info)?;
+        let expected = Pubkey::create_program_address(
+            &[b"player", game_key.as_ref(), &[i as u8], &[player.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| DeadmintError::InvalidPlayerAccounts)?;
+        require_keys_eq!(player_info.key(), expected, DeadmintError::InvalidPlayerAccounts);
+        require_keys_eq!(player.game, game_key, DeadmintError::PlayerGameMismatch);
+        require_keys_eq!(payer_info.key(), player.payer, DeadmintError::InvalidPlayerAccounts);
+
+        // Refund the wager from the Game PDA / vault
+        let wager = player.wager;
+        stake::pay_out(
+            game,
+            token_stake.as_ref(),
+            payer_info,
+            payer_token_account.as_ref(),
+            wager,
+        )?;
+        game.prize_pool = game
+            .prize_pool
+            .checked_sub(wager)
+            .ok_or(DeadmintError::MathOverflow)?;
+
+        // Close the Player account, returning its rent to the payer
+        player.close(payer_info.clone())?;
+    }
+
+    if let Some(token_stake) = token_stake.as_ref() {
+        stake::close_vault(
+            game,
+            token_stake,
+            &ctx.accounts.authority.to_account_info(),
+            ctx.accounts.authority_token_account.as_ref(),
+        )?;
+    }
+
+    emit!(GameCancelled {
+        game: game_key,
+        refunded: game.current_players,
+    });
+
+    msg!("Game {} cancelled, {} players refunded", game.game_id, game.current_players);
+
+    Ok(())
+}
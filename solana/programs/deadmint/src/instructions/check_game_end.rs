@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use deadmint_engine::{self as engine, Action, Now, PlayerState};
 use crate::state::*;
 use crate::errors::DeadmintError;
+use crate::events::emit_engine_events;
 
 #[derive(Accounts)]
 pub struct CheckGameEnd<'info> {
@@ -11,62 +13,55 @@ pub struct CheckGameEnd<'info> {
     )]
     pub game: Account<'info, Game>,
     pub authority: Signer<'info>,
-    // remaining_accounts: all Player accounts for alive counting
+    // remaining_accounts: every Player account of the game, in index order
 }
 
 pub fn handler(ctx: Context<CheckGameEnd>) -> Result<()> {
     let game = &mut ctx.accounts.game;
 
-    require!(game.status == STATUS_ACTIVE, DeadmintError::GameNotActive);
+    // Count alive players over the complete Player set, so the caller can't
+    // pick the outcome by leaving players out
+    let mut players = Player::load_set(&game.key(), game.current_players, ctx.remaining_accounts)?;
 
-    // Check timer expiry (round_duration == 0 means no time limit)
     let clock = Clock::get()?;
-    let timed_out = game.round_duration > 0
-        && game.started_at > 0
-        && (clock.unix_timestamp - game.started_at) >= game.round_duration as i64;
+    let now = Now { slot: clock.slot, unix_timestamp: clock.unix_timestamp };
 
-    // Count alive players from remaining_accounts (trustless, on-chain!)
-    let program_id = crate::ID;
-    let mut alive_count: u8 = 0;
-    let mut last_alive_authority = Pubkey::default();
+    let mut state = Box::new(game.to_state());
+    let mut player_states: Vec<PlayerState> = players.iter().map(Player::to_state).collect();
+    let events = engine::step(&mut state, &mut player_states, Action::CheckEnd, now)
+        .map_err(DeadmintError::from)?;
 
-    for acc_info in ctx.remaining_accounts.iter() {
-        if acc_info.owner != &program_id {
-            continue;
-        }
-
-        let data = acc_info.try_borrow_data()?;
-        if data.len() < Player::SIZE {
-            continue;
-        }
-
-        // Check discriminator
-        let disc = &data[..8];
-        if disc != Player::DISCRIMINATOR {
-            continue;
-        }
-
-        // Verify player belongs to this game
-        let player_game = Pubkey::try_from(&data[8..40]).unwrap();
-        if player_game != game.key() {
-            continue;
-        }
-
-        let player_authority = Pubkey::try_from(&data[40..72]).unwrap();
-        let alive = data[75] != 0;
-
-        if alive {
-            alive_count += 1;
-            last_alive_authority = player_authority;
+    game.apply_state(&state);
+    for ((player, player_state), acc_info) in players
+        .iter_mut()
+        .zip(player_states.iter())
+        .zip(ctx.remaining_accounts.iter())
+    {
+        // Players standing on a live explosion were eliminated by the check
+        if player.alive != player_state.alive {
+            player.apply_state(player_state);
+            player.store(acc_info)?;
         }
     }
 
-    if alive_count <= 1 || timed_out {
-        game.status = STATUS_FINISHED;
-        if alive_count == 1 {
-            game.winner = last_alive_authority;
+    if game.status == STATUS_FINISHED {
+        // Fix the distributable pool now; the fee is sent to the treasury by
+        // the first claim (the treasury is not delegated to the rollup).
+        game.platform_fee_due = game
+            .prize_pool
+            .checked_mul(game.platform_fee_bps as u64)
+            .ok_or(DeadmintError::MathOverflow)?
+            / 10_000;
+        game.payout_pool = game.prize_pool - game.platform_fee_due;
+
+        // Nothing to pay out (free game, or loot and bounties drained the
+        // pool): no claim can ever succeed, so the game is settled right away
+        // and its accounts can be closed
+        if game.all_prizes_claimed() {
+            game.status = STATUS_CLAIMED;
         }
     }
+    emit_engine_events(game.key(), game, events);
 
     Ok(())
 }
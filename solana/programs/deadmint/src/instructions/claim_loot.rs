@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::DeadmintError;
+use crate::events::LootClaimed;
+use crate::stake;
+
+#[derive(Accounts)]
+pub struct ClaimLoot<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.game_id.to_le_bytes().as_ref()],
+        bump = game.bump,
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"player", game.key().as_ref(), &[player.player_index]],
+        bump = player.bump,
+        constraint = player.game == game.key() @ DeadmintError::PlayerGameMismatch,
+        has_one = payer @ DeadmintError::Unauthorized,
+    )]
+    pub player: Account<'info, Player>,
+    /// CHECK: The wallet that paid the player's wager (receives the loot payout)
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    // Token accounts — only for SPL-token games
+    #[account(address = game.mint @ DeadmintError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"vault", game.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Player's token account (owned by `payer`)
+    #[account(mut)]
+    pub payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<ClaimLoot>) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let player = &mut ctx.accounts.player;
+
+    // Loot can be settled once the game is over, before or after the winner claims
+    require!(
+        game.status == STATUS_FINISHED || game.status == STATUS_CLAIMED,
+        DeadmintError::GameNotFinished
+    );
+    require!(player.collected_sol > 0, DeadmintError::NothingToClaim);
+
+    let loot_payout = player.collected_sol;
+
+    // Loot was already deducted from prize_pool at pickup, so these funds
+    // are not part of the winner's share.
+    let token_stake = stake::token_stake(
+        game,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    stake::pay_out(
+        game,
+        token_stake.as_ref(),
+        &ctx.accounts.payer.to_account_info(),
+        ctx.accounts.payer_token_account.as_ref(),
+        loot_payout,
+    )?;
+
+    player.collected_sol = 0;
+
+    emit!(LootClaimed {
+        game: game.key(),
+        player: ctx.accounts.payer.key(),
+        amount: loot_payout,
+    });
+
+    msg!("Loot payout: {} to player {}", loot_payout, player.player_index);
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::*;
+use crate::events::PrizeClaimed;
 use crate::errors::DeadmintError;
+use crate::stake;
 
 #[derive(Accounts)]
 pub struct ClaimPrize<'info> {
@@ -14,54 +17,117 @@ pub struct ClaimPrize<'info> {
         seeds = [b"player", game.key().as_ref(), &[player.player_index]],
         bump = player.bump,
         constraint = player.game == game.key() @ DeadmintError::PlayerGameMismatch,
+        has_one = payer @ DeadmintError::Unauthorized,
     )]
     pub player: Account<'info, Player>,
-    /// The winner's wallet (receives SOL payout)
+    /// Protocol treasury (receives the platform fee)
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    /// CHECK: The wallet that paid the player's wager (receives the payout).
+    /// Bound to the Player account, so a session key cannot redirect it.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    // Token accounts — only for SPL-token games
+    #[account(address = game.mint @ DeadmintError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"vault", game.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Player's token account (owned by `payer`)
     #[account(mut)]
-    pub winner: Signer<'info>,
+    pub payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"treasury_vault", game.mint.as_ref()], bump)]
+    pub treasury_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
+/// Pays one placed player their share of the pool. Each player with a non-zero
+/// share claims once; the game becomes CLAIMED when the last share is paid.
 pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
     let game = &mut ctx.accounts.game;
     let player = &ctx.accounts.player;
 
     require!(game.status == STATUS_FINISHED, DeadmintError::GameNotFinished);
-    require!(game.winner != Pubkey::default(), DeadmintError::NoWinner);
-    require!(game.prize_pool > 0, DeadmintError::AlreadyClaimed);
 
-    // Winner is identified by player.authority (session key).
-    // But claim_prize is called by the actual wallet.
-    // So we check: game.winner == player.authority (the session key that won)
-    // and the winner signer can be anyone who can prove they own this player.
-    // For simplicity: game.winner must match player.authority
-    require!(game.winner == player.authority, DeadmintError::NotWinner);
+    // The player is identified by its PDA, but the payout always goes to
+    // player.payer — the wallet that funded the wager. Anyone may submit the
+    // claim since the destination is fixed.
+    let player_index = player.player_index as usize;
+    let bit = 1u8 << player_index;
+    require!(game.claimed_mask & bit == 0, DeadmintError::AlreadyClaimed);
 
-    // Calculate payouts
-    let platform_fee = game
-        .prize_pool
-        .checked_mul(game.platform_fee_bps as u64)
-        .ok_or(DeadmintError::MathOverflow)?
-        / 10_000;
-    let winner_payout = game
-        .prize_pool
-        .checked_sub(platform_fee)
-        .ok_or(DeadmintError::MathOverflow)?;
+    // Shares come from the pool fixed at game end (loot picked up during the
+    // game was already moved into player balances, paid via claim_loot)
+    let share = game.placement_share(player_index);
+    require!(share > 0, DeadmintError::NotPlaced);
 
-    // Transfer SOL from Game PDA to winner
-    let game_info = game.to_account_info();
-    let winner_info = ctx.accounts.winner.to_account_info();
+    let token_stake = stake::token_stake(
+        game,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
 
-    **game_info.try_borrow_mut_lamports()? -= winner_payout;
-    **winner_info.try_borrow_mut_lamports()? += winner_payout;
+    // Transfer platform fee to the treasury on the first claim
+    // (token fees go to its vault for this mint)
+    let platform_fee = game.platform_fee_due;
+    if platform_fee > 0 {
+        let treasury = &mut ctx.accounts.treasury;
+        stake::pay_out(
+            game,
+            token_stake.as_ref(),
+            &treasury.to_account_info(),
+            ctx.accounts.treasury_vault.as_ref(),
+            platform_fee,
+        )?;
 
-    game.prize_pool = 0;
-    game.status = STATUS_CLAIMED;
+        if !game.is_token_game() {
+            treasury.total_fees_collected = treasury
+                .total_fees_collected
+                .checked_add(platform_fee)
+                .ok_or(DeadmintError::MathOverflow)?;
+        }
+
+        game.prize_pool = game
+            .prize_pool
+            .checked_sub(platform_fee)
+            .ok_or(DeadmintError::MathOverflow)?;
+        game.platform_fee_due = 0;
+    }
+
+    // The last claimant also receives any rounding dust, draining the pool
+    game.claimed_mask |= bit;
+    let all_claimed = game.all_prizes_claimed();
+    let payout = if all_claimed { game.prize_pool } else { share };
+
+    // Transfer the prize from the Game PDA / vault to the player's wallet
+    stake::pay_out(
+        game,
+        token_stake.as_ref(),
+        &ctx.accounts.payer.to_account_info(),
+        ctx.accounts.payer_token_account.as_ref(),
+        payout,
+    )?;
+
+    game.prize_pool = game
+        .prize_pool
+        .checked_sub(payout)
+        .ok_or(DeadmintError::MathOverflow)?;
+    if all_claimed {
+        game.status = STATUS_CLAIMED;
+    }
 
-    msg!(
-        "Winner payout: {} lamports, platform fee: {} lamports",
-        winner_payout,
+    emit!(PrizeClaimed {
+        game: game.key(),
+        player_index: player_index as u8,
+        payer: ctx.accounts.payer.key(),
+        place: game.placements[player_index],
+        amount: payout,
         platform_fee,
-    );
+    });
 
     Ok(())
 }
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::DeadmintError;
+use crate::events::GameClosed;
+use crate::stake;
+
+/// Permissionless cleanup of a settled Game account once every Player
+/// account is closed. Rent goes back to the creator who paid for it.
+#[derive(Accounts)]
+pub struct CloseGame<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.game_id.to_le_bytes().as_ref()],
+        bump = game.bump,
+        close = authority,
+    )]
+    pub game: Account<'info, Game>,
+    /// CHECK: Game creator — paid for the Game account
+    #[account(mut, address = game.authority @ DeadmintError::Unauthorized)]
+    pub authority: UncheckedAccount<'info>,
+    // Token accounts — only for SPL-token games (the vault is closed too)
+    #[account(address = game.mint @ DeadmintError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"vault", game.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Creator's token account for any leftover vault balance
+    #[account(mut)]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<CloseGame>) -> Result<()> {
+    let game = &ctx.accounts.game;
+
+    require!(game.status == STATUS_CLAIMED, DeadmintError::GameNotSettled);
+    require!(
+        game.closed_players == game.current_players,
+        DeadmintError::PlayersNotClosed
+    );
+
+    let token_stake = stake::token_stake(
+        game,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    if let Some(token_stake) = token_stake.as_ref() {
+        stake::close_vault(
+            game,
+            token_stake,
+            &ctx.accounts.authority.to_account_info(),
+            ctx.accounts.authority_token_account.as_ref(),
+        )?;
+    }
+
+    emit!(GameClosed {
+        game: game.key(),
+        authority: game.authority,
+    });
+
+    Ok(())
+}
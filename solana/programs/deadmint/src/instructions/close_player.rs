@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::DeadmintError;
+use crate::events::PlayerClosed;
+use crate::stake;
+
+/// Permissionless cleanup of a Player account once the game is settled.
+/// Rent and any unclaimed loot go back to the wallet that joined.
+#[derive(Accounts)]
+pub struct ClosePlayer<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.game_id.to_le_bytes().as_ref()],
+        bump = game.bump,
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"player", game.key().as_ref(), &[player.player_index]],
+        bump = player.bump,
+        constraint = player.game == game.key() @ DeadmintError::PlayerGameMismatch,
+        has_one = payer @ DeadmintError::Unauthorized,
+        close = payer,
+    )]
+    pub player: Account<'info, Player>,
+    /// CHECK: Wallet that paid for the Player account
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    // Token accounts — only for SPL-token games
+    #[account(address = game.mint @ DeadmintError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"vault", game.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Player's token account for unclaimed loot (owned by `payer`)
+    #[account(mut)]
+    pub payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<ClosePlayer>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let player = &mut ctx.accounts.player;
+
+    require!(game.status == STATUS_CLAIMED, DeadmintError::GameNotSettled);
+
+    // Sweep loot that was never claimed, so it is not stranded in the game
+    let unclaimed_loot = player.collected_sol;
+    if unclaimed_loot > 0 {
+        let token_stake = stake::token_stake(
+            game,
+            &ctx.accounts.mint,
+            &ctx.accounts.vault,
+            &ctx.accounts.token_program,
+        )?;
+        stake::pay_out(
+            game,
+            token_stake.as_ref(),
+            &ctx.accounts.payer.to_account_info(),
+            ctx.accounts.payer_token_account.as_ref(),
+            unclaimed_loot,
+        )?;
+
+        player.collected_sol = 0;
+    }
+
+    game.closed_players += 1;
+
+    emit!(PlayerClosed {
+        game: game.key(),
+        player_index: player.player_index,
+        payer: ctx.accounts.payer.key(),
+        unclaimed_loot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::program::Deadmint;
+use crate::state::*;
+use crate::errors::DeadmintError;
+
+/// Creates the global protocol config PDA. Only the program's upgrade authority
+/// can run it, and it becomes the config admin, which also controls the treasury.
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Config::SIZE,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Deadmint>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ DeadmintError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+    params.validate()?;
+
+    let config = &mut ctx.accounts.config;
+
+    config.admin = ctx.accounts.admin.key();
+    config.apply(&params);
+    config.bump = ctx.bumps.config;
+
+    Ok(())
+}
@@ -1,79 +1,151 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use deadmint_engine::map::{self, MapOptions};
 use crate::state::*;
+use crate::events::GameCreated;
+use crate::randomness;
+use crate::errors::DeadmintError;
 
 #[derive(Accounts)]
-#[instruction(game_id: u64)]
+#[instruction(game_id: u64, params: GameParams)]
 pub struct InitializeGame<'info> {
     #[account(
         init,
         payer = payer,
-        space = Game::SIZE,
+        space = Game::space(params.grid_cells(), params.bomb_slots()),
         seeds = [b"game", game_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub game: Account<'info, Game>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ DeadmintError::ProtocolPaused,
+    )]
+    pub config: Account<'info, Config>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// SPL Token or Token-2022 mint for token-denominated wagers (omit for native SOL)
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// Game-owned vault that holds the token stake (only with `mint`)
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"vault", game.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = game,
+        token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Treasury vault for `mint`, which receives the platform fee (only with `mint`)
+    #[account(
+        seeds = [b"treasury_vault", mint.as_ref().map(|m| m.key()).unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub treasury_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitializeGame>, game_id: u64, entry_fee: u64, max_players: u8) -> Result<()> {
+pub fn handler(ctx: Context<InitializeGame>, game_id: u64, params: GameParams) -> Result<()> {
+    params.validate()?;
+
+    let config = &ctx.accounts.config;
+    require!(
+        params.platform_fee_bps <= config.max_platform_fee_bps,
+        DeadmintError::FeeTooHigh
+    );
+    // The range is in lamports: token amounts have no common unit across mints
+    if ctx.accounts.mint.is_none() {
+        require!(
+            params.entry_fee >= config.min_entry_fee && params.entry_fee <= config.max_entry_fee,
+            DeadmintError::EntryFeeOutOfRange
+        );
+    }
+
+    // A token game needs its vault, and a treasury vault to take the fee,
+    // or its claims could never go through
+    let accounts = &ctx.accounts;
+    require!(
+        accounts.mint.is_some() == accounts.vault.is_some(),
+        DeadmintError::MissingTokenAccounts
+    );
+    require!(
+        accounts.mint.is_none() || params.platform_fee_bps == 0 || accounts.treasury_vault.is_some(),
+        DeadmintError::MissingTreasuryVault
+    );
+
     let game = &mut ctx.accounts.game;
 
     game.game_id = game_id;
     game.authority = ctx.accounts.payer.key();
-    game.grid_width = GRID_WIDTH;
-    game.grid_height = GRID_HEIGHT;
-    game.max_players = max_players;
+    game.grid_width = params.grid_width;
+    game.grid_height = params.grid_height;
+    game.max_players = params.max_players;
     game.current_players = 0;
-    game.entry_fee = entry_fee;
+    game.entry_fee = params.entry_fee;
+    game.mint = ctx.accounts.mint.as_ref().map(|m| m.key()).unwrap_or_default();
     game.prize_pool = 0;
     game.status = STATUS_LOBBY;
     game.winner = Pubkey::default();
-    game.round_duration = 0; // No time limit
-    game.platform_fee_bps = 300; // 3%
+    game.round_duration = params.round_duration;
+    game.platform_fee_bps = params.platform_fee_bps;
+    game.bounty_bps = params.bounty_bps;
+    game.fuse_slots = params.fuse_slots;
+    game.explosion_duration_slots = params.explosion_duration_slots;
+    game.start_bomb_range = params.start_bomb_range;
+    game.start_max_bombs = params.start_max_bombs;
+    game.start_speed = params.start_speed;
+    game.bomb_range_cap = params.bomb_range_cap;
+    game.max_bombs_cap = params.max_bombs_cap;
+    game.speed_cap = params.speed_cap;
     game.bump = ctx.bumps.game;
 
     let clock = Clock::get()?;
     game.created_at = clock.unix_timestamp;
     game.started_at = 0;
+    game.seed = randomness::initial_seed(&game.key(), game_id, &game.authority, clock.slot);
 
-    // Generate classic Bomberman grid (13×11)
-    for y in 0..GRID_HEIGHT {
-        for x in 0..GRID_WIDTH {
-            let idx = (y as usize) * (GRID_WIDTH as usize) + (x as usize);
-            if x == 0 || x == 12 || y == 0 || y == 10 {
-                // Border walls
-                game.cells[idx] = CELL_WALL;
-            } else if x % 2 == 0 && y % 2 == 0 {
-                // Indestructible pillars at even x,y
-                game.cells[idx] = CELL_WALL;
-            } else if is_spawn_safe_zone(x, y) {
-                // Keep spawn corners clear
-                game.cells[idx] = CELL_EMPTY;
-            } else {
-                // Destructible block
-                game.cells[idx] = CELL_BLOCK;
-            }
-        }
+    // Generate the arena from the game seed, then check every spawn is playable
+    let spawns = map::spawn_points(game.grid_width, game.grid_height, game.max_players as usize);
+    let (cells, powerup_types) = map::generate(
+        game.grid_width,
+        game.grid_height,
+        &spawns,
+        &MapOptions::from(&params.map),
+        game.seed,
+    )
+    .map_err(DeadmintError::from)?;
+    game.cells = cells;
+    game.powerup_types = powerup_types;
+    game.spawns = [SpawnPoint::default(); MAX_PLAYERS];
+    for (slot, &(x, y)) in game.spawns.iter_mut().zip(&spawns) {
+        *slot = SpawnPoint { x, y };
     }
+    game.seal_map()?;
 
-    // Initialize bombs and powerup_types to zeros (already default)
+    // One bomb slot per bomb the lobby can have out at once
+    game.bombs = vec![BombSlot::default(); params.bomb_slots()];
     game.bomb_count = 0;
-    game.last_detonate_slot = 0;
+    game.start_slot = 0;
+    game.explosion_expiry = vec![0; game.cells.len()];
+    game.closed_players = 0;
+    game.payout_bps = params.payout_bps;
+    game.placements = [0; MAX_PLAYERS];
+    game.claimed_mask = 0;
+    game.payout_pool = 0;
+    game.platform_fee_due = 0;
 
-    Ok(())
-}
+    emit!(GameCreated {
+        game: game.key(),
+        game_id,
+        authority: game.authority,
+        mint: game.mint,
+        entry_fee: game.entry_fee,
+        max_players: game.max_players,
+        map_hash: game.map_hash,
+    });
 
-/// Spawn corners at (1,1), (11,1), (1,9), (11,9).
-/// Keep cells clear within Manhattan distance <= 2 of each spawn.
-fn is_spawn_safe_zone(x: u8, y: u8) -> bool {
-    for (sx, sy) in SPAWN_POSITIONS {
-        let dx = if x > sx { x - sx } else { sx - x };
-        let dy = if y > sy { y - sy } else { sy - y };
-        if dx + dy <= 2 {
-            return true;
-        }
-    }
-    false
+    Ok(())
 }
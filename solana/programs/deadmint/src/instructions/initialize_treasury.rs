@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::program::Deadmint;
+use crate::state::*;
+use crate::errors::DeadmintError;
+
+/// Creates the global treasury PDA that accrues platform fees.
+/// Only the program's upgrade authority can run it; fees are withdrawn by the
+/// config admin.
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::SIZE,
+        seeds = [b"treasury"],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Deadmint>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ DeadmintError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTreasury>) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+
+    treasury.total_fees_collected = 0;
+    treasury.total_fees_withdrawn = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::DeadmintError;
+
+/// Creates the treasury-owned token account that accrues platform fees for
+/// one SPL mint. Needed before games staked in that mint can be claimed.
+#[derive(Accounts)]
+pub struct InitializeTreasuryVault<'info> {
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ DeadmintError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"treasury_vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTreasuryVault>) -> Result<()> {
+    msg!("Treasury vault created for mint {}", ctx.accounts.mint.key());
+    Ok(())
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::*;
 use crate::errors::DeadmintError;
+use crate::events::{GameStarted, PlayerJoined};
+use crate::stake;
+use crate::randomness;
+use anchor_lang::solana_program::sysvar::slot_hashes;
 
 #[derive(Accounts)]
 pub struct JoinGame<'info> {
@@ -11,6 +15,12 @@ pub struct JoinGame<'info> {
         bump = game.bump,
     )]
     pub game: Account<'info, Game>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ DeadmintError::ProtocolPaused,
+    )]
+    pub config: Account<'info, Config>,
     #[account(
         init,
         payer = payer,
@@ -21,10 +31,22 @@ pub struct JoinGame<'info> {
     pub player: Account<'info, Player>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    // Token accounts — only for SPL-token games
+    #[account(address = game.mint @ DeadmintError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"vault", game.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Payer's token account the entry fee is taken from
+    #[account(mut)]
+    pub payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: SlotHashes sysvar — fixes the game seed when the lobby fills
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<JoinGame>, player_authority: Pubkey) -> Result<()> {
+pub fn handler(ctx: Context<JoinGame>, player_authority: Pubkey, entropy: [u8; 32]) -> Result<()> {
     let game = &mut ctx.accounts.game;
     let player = &mut ctx.accounts.player;
 
@@ -32,7 +54,7 @@ pub fn handler(ctx: Context<JoinGame>, player_authority: Pubkey) -> Result<()> {
     require!(game.current_players < game.max_players, DeadmintError::GameFull);
 
     let idx = game.current_players as usize;
-    let (spawn_x, spawn_y) = SPAWN_POSITIONS[idx];
+    let SpawnPoint { x: spawn_x, y: spawn_y } = game.spawns[idx];
 
     // Initialize player
     player.game = game.key();
@@ -42,41 +64,71 @@ pub fn handler(ctx: Context<JoinGame>, player_authority: Pubkey) -> Result<()> {
     player.y = spawn_y;
     player.alive = true;
     player.collected_sol = 0;
-    player.wager = game.entry_fee;
-    player.bomb_range = 1;
-    player.max_bombs = 1;
+    player.bomb_range = game.start_bomb_range;
+    player.max_bombs = game.start_max_bombs;
     player.active_bombs = 0;
-    player.speed = 1;
+    player.speed = game.start_speed;
     player.last_move_slot = 0;
     player.kills = 0;
     player.input_nonce = 0;
+    player.eliminated_at_slot = 0;
+    player.payer = ctx.accounts.payer.key();
     player.bump = ctx.bumps.player;
 
-    // Transfer entry fee from payer to game account
-    if game.entry_fee > 0 {
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.payer.to_account_info(),
-                    to: game.to_account_info(),
-                },
-            ),
-            game.entry_fee,
-        )?;
-    }
+    // Transfer entry fee from payer to game account (SOL) or vault (tokens).
+    // The wager is what actually arrived, net of any Token-2022 transfer fee.
+    let token_stake = stake::token_stake(
+        game,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    let wager = stake::pay_in(
+        game,
+        token_stake.as_ref(),
+        &ctx.accounts.payer,
+        ctx.accounts.payer_token_account.as_ref(),
+        &ctx.accounts.system_program,
+        game.entry_fee,
+    )?;
+    player.wager = wager;
 
     game.current_players += 1;
     game.prize_pool = game
         .prize_pool
-        .checked_add(game.entry_fee)
+        .checked_add(wager)
         .ok_or(DeadmintError::MathOverflow)?;
 
+    // Every player's contribution goes into the game seed
+    game.seed = randomness::mix(&game.seed, &entropy);
+
+    emit!(PlayerJoined {
+        game: game.key(),
+        player_index: player.player_index,
+        authority: player.authority,
+        payer: player.payer,
+        wager,
+    });
+
     // Auto-start when full
     if game.current_players == game.max_players {
         game.status = STATUS_ACTIVE;
         let clock = Clock::get()?;
         game.started_at = clock.unix_timestamp;
+        game.start_slot = clock.slot;
+        game.seed = randomness::mix(
+            &game.seed,
+            &randomness::latest_slot_hash(&ctx.accounts.slot_hashes)?,
+        );
+
+        emit!(GameStarted {
+            game: game.key(),
+            players: game.current_players,
+            prize_pool: game.prize_pool,
+            start_slot: game.start_slot,
+            started_at: game.started_at,
+            seed: game.seed,
+        });
     }
 
     Ok(())
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::DeadmintError;
+use crate::events::PlayerLeft;
+use crate::stake;
+
+#[derive(Accounts)]
+pub struct LeaveLobby<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.game_id.to_le_bytes().as_ref()],
+        bump = game.bump,
+    )]
+    pub game: Account<'info, Game>,
+    /// The leaving player
+    #[account(
+        mut,
+        seeds = [b"player", game.key().as_ref(), &[player.player_index]],
+        bump = player.bump,
+        constraint = player.game == game.key() @ DeadmintError::PlayerGameMismatch,
+        constraint = player.payer == payer.key() @ DeadmintError::Unauthorized,
+    )]
+    pub player: Account<'info, Player>,
+    /// The most recently joined player, moved into the leaver's slot.
+    /// Omit when the leaver is the last player.
+    #[account(
+        mut,
+        seeds = [b"player", game.key().as_ref(), &[game.current_players.saturating_sub(1)]],
+        bump = last_player.bump,
+    )]
+    pub last_player: Option<Account<'info, Player>>,
+    /// Wallet that paid the wager (receives the refund and rent)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    // Token accounts — only for SPL-token games
+    #[account(address = game.mint @ DeadmintError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"vault", game.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Payer's token account for the refund
+    #[account(mut)]
+    pub payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<LeaveLobby>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let player = &mut ctx.accounts.player;
+
+    require!(game.status == STATUS_LOBBY, DeadmintError::GameNotInLobby);
+
+    // Refund the wager from the Game PDA / vault
+    let wager = player.wager;
+    let payer_info = ctx.accounts.payer.to_account_info();
+    let token_stake = stake::token_stake(
+        game,
+        &ctx.accounts.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.token_program,
+    )?;
+    stake::pay_out(
+        game,
+        token_stake.as_ref(),
+        &payer_info,
+        ctx.accounts.payer_token_account.as_ref(),
+        wager,
+    )?;
+
+    game.prize_pool = game
+        .prize_pool
+        .checked_sub(wager)
+        .ok_or(DeadmintError::MathOverflow)?;
+
+    let last_index = game.current_players - 1;
+    let leaving_index = player.player_index;
+
+    if leaving_index == last_index {
+        require!(ctx.accounts.last_player.is_none(), DeadmintError::InvalidPlayerAccounts);
+        player.close(payer_info)?;
+    } else {
+        // Keep player indices dense so `[b"player", game, current_players]` stays
+        // the next free seed: the last joiner takes over the leaver's PDA, index
+        // and spawn, and the last PDA is closed. Both accounts have the same size,
+        // so the leaver is refunded the rent of the closed one.
+        let last_player = ctx
+            .accounts
+            .last_player
+            .as_mut()
+            .ok_or(DeadmintError::InvalidPlayerAccounts)?;
+        require_keys_eq!(last_player.game, game.key(), DeadmintError::PlayerGameMismatch);
+
+        let SpawnPoint { x: spawn_x, y: spawn_y } = game.spawns[leaving_index as usize];
+        let mut moved = Player::clone(last_player);
+        moved.player_index = leaving_index;
+        moved.x = spawn_x;
+        moved.y = spawn_y;
+        moved.bump = player.bump;
+        player.set_inner(moved);
+
+        last_player.close(payer_info)?;
+    }
+
+    game.current_players -= 1;
+
+    emit!(PlayerLeft {
+        game: game.key(),
+        player: ctx.accounts.payer.key(),
+        moved_from: last_index,
+        moved_to: leaving_index,
+    });
+
+    msg!("Player left game {}, {} players remain", game.game_id, game.current_players);
+
+    Ok(())
+}
@@ -1,21 +1,45 @@
 #![allow(ambiguous_glob_reexports)]
 
 pub mod initialize_game;
+pub mod set_map;
 pub mod join_game;
+pub mod leave_lobby;
+pub mod cancel_game;
 pub mod move_player;
 pub mod place_bomb;
 pub mod detonate_bomb;
 pub mod check_game_end;
 pub mod claim_prize;
+pub mod claim_loot;
+pub mod close_player;
+pub mod close_game;
+pub mod initialize_treasury;
+pub mod initialize_treasury_vault;
+pub mod withdraw_fees;
+pub mod initialize_config;
+pub mod update_config;
+pub mod transfer_admin;
 pub mod delegate;
 pub mod undelegate;
 
 pub use initialize_game::*;
+pub use set_map::*;
 pub use join_game::*;
+pub use leave_lobby::*;
+pub use cancel_game::*;
 pub use move_player::*;
 pub use place_bomb::*;
 pub use detonate_bomb::*;
 pub use check_game_end::*;
 pub use claim_prize::*;
+pub use claim_loot::*;
+pub use close_player::*;
+pub use close_game::*;
+pub use initialize_treasury::*;
+pub use initialize_treasury_vault::*;
+pub use withdraw_fees::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use transfer_admin::*;
 pub use delegate::*;
 pub use undelegate::*;
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use deadmint_engine::{self as engine, Action, Now};
 use crate::state::*;
 use crate::errors::DeadmintError;
+use crate::events::emit_engine_events;
 
 #[derive(Accounts)]
 pub struct PlaceBomb<'info> {
@@ -10,6 +12,12 @@ pub struct PlaceBomb<'info> {
         bump = game.bump,
     )]
     pub game: Account<'info, Game>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ DeadmintError::ProtocolPaused,
+    )]
+    pub config: Account<'info, Config>,
     #[account(
         mut,
         seeds = [b"player", game.key().as_ref(), &[player.player_index]],
@@ -25,41 +33,17 @@ pub fn handler(ctx: Context<PlaceBomb>) -> Result<()> {
     let game = &mut ctx.accounts.game;
     let player = &mut ctx.accounts.player;
 
-    require!(game.status == STATUS_ACTIVE, DeadmintError::GameNotActive);
-    require!(player.alive, DeadmintError::PlayerNotAlive);
-    require!(
-        player.active_bombs < player.max_bombs,
-        DeadmintError::NoBombsAvailable
-    );
-
-    let idx = game.cell_idx(player.x, player.y);
-    require!(
-        game.cells[idx] == CELL_EMPTY || game.cells[idx] == CELL_LOOT || game.cells[idx] == CELL_POWERUP,
-        DeadmintError::CellOccupied
-    );
-
-    // Find a free bomb slot
-    let slot_idx = game.find_free_bomb_slot().ok_or(DeadmintError::BombSlotsFull)?;
-
-    // Mark cell as bomb on the grid
-    game.cells[idx] = CELL_BOMB;
-
-    // Initialize bomb slot
     let clock = Clock::get()?;
-    game.bombs[slot_idx] = BombSlot {
-        active: true,
-        owner: player.authority,
-        x: player.x,
-        y: player.y,
-        range: player.bomb_range,
-        fuse_slots: 8, // ~3 seconds at ~400ms slots
-        placed_at_slot: clock.slot,
-        detonated: false,
-    };
-    game.bomb_count += 1;
+    let now = Now { slot: clock.slot, unix_timestamp: clock.unix_timestamp };
+
+    let mut state = Box::new(game.to_state());
+    let mut players = [player.to_state()];
+    let events = engine::step(&mut state, &mut players, Action::PlaceBomb { player: 0 }, now)
+        .map_err(DeadmintError::from)?;
 
-    player.active_bombs += 1;
-    player.input_nonce += 1;
+    game.apply_state(&state);
+    player.apply_state(&players[0]);
+    emit_engine_events(game.key(), game, events);
 
     Ok(())
 }
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DeadmintError;
+use crate::events::MapSet;
+
+#[derive(Accounts)]
+pub struct SetMap<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.game_id.to_le_bytes().as_ref()],
+        bump = game.bump,
+        has_one = authority @ DeadmintError::Unauthorized,
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ DeadmintError::ProtocolPaused,
+    )]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMap>, layout: MapLayout) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+
+    require!(game.status == STATUS_LOBBY, DeadmintError::GameNotInLobby);
+    // Joined players already stand on the old spawns
+    require!(game.current_players == 0, DeadmintError::MapLocked);
+
+    let total = (game.grid_width as usize) * (game.grid_height as usize);
+    require!(
+        layout.cells.len() == total
+            && layout.powerup_types.len() == total
+            && layout.spawns.len() == game.max_players as usize,
+        DeadmintError::InvalidMapSize
+    );
+
+    game.cells = layout.cells;
+    game.powerup_types = layout.powerup_types;
+    game.spawns = [SpawnPoint::default(); MAX_PLAYERS];
+    game.spawns[..layout.spawns.len()].copy_from_slice(&layout.spawns);
+
+    // Border, spawns and reachability are checked before the hash is recorded
+    game.seal_map()?;
+
+    emit!(MapSet {
+        game: game.key(),
+        authority: game.authority,
+        map_hash: game.map_hash,
+    });
+
+    Ok(())
+}
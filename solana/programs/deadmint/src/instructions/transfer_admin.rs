@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DeadmintError;
+
+/// Hands the config over to a new admin. The new admin must co-sign, so the
+/// config can't be transferred to a mistyped or unowned key.
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ DeadmintError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    pub new_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<TransferAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.admin = ctx.accounts.new_admin.key();
+
+    msg!("Config admin transferred to {}", config.admin);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::DeadmintError;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ DeadmintError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+    params.validate()?;
+
+    ctx.accounts.config.apply(&params);
+
+    msg!(
+        "Config updated: max fee {} bps, entry fee {}..={}, paused {}",
+        params.max_platform_fee_bps,
+        params.min_entry_fee,
+        params.max_entry_fee,
+        params.paused
+    );
+
+    Ok(())
+}
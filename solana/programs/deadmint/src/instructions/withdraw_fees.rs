@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::DeadmintError;
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ DeadmintError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    /// CHECK: Any wallet chosen by the admin to receive the fees
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    // Token accounts — only to withdraw fees accrued in an SPL mint
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", mint.as_ref().map(|m| m.key()).unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub treasury_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::authority = recipient)]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    if let Some(treasury_vault) = ctx.accounts.treasury_vault.as_ref() {
+        return withdraw_token_fees(&ctx, treasury_vault, amount);
+    }
+
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_info = treasury.to_account_info();
+
+    // Never dip into the rent-exempt reserve of the treasury account
+    let rent_reserve = Rent::get()?.minimum_balance(Treasury::SIZE);
+    let available = treasury_info.lamports().saturating_sub(rent_reserve);
+    require!(amount > 0 && amount <= available, DeadmintError::InsufficientFees);
+
+    let recipient_info = ctx.accounts.recipient.to_account_info();
+
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **recipient_info.try_borrow_mut_lamports()? += amount;
+
+    treasury.total_fees_withdrawn = treasury
+        .total_fees_withdrawn
+        .checked_add(amount)
+        .ok_or(DeadmintError::MathOverflow)?;
+
+    msg!("Withdrew {} lamports of platform fees", amount);
+
+    Ok(())
+}
+
+/// Withdraws `amount` of token fees from the treasury vault of one mint.
+fn withdraw_token_fees<'info>(
+    ctx: &Context<WithdrawFees<'info>>,
+    treasury_vault: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    let (Some(mint), Some(recipient_token_account), Some(token_program)) = (
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.recipient_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    ) else {
+        return err!(DeadmintError::MissingTokenAccounts);
+    };
+    require!(amount > 0 && amount <= treasury_vault.amount, DeadmintError::InsufficientFees);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"treasury", &[ctx.accounts.treasury.bump]]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: treasury_vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: recipient_token_account.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    msg!("Withdrew {} of platform fees in mint {}", amount, mint.key());
+
+    Ok(())
+}
@@ -2,10 +2,14 @@ use anchor_lang::prelude::*;
 use ephemeral_rollups_sdk::anchor::ephemeral;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod randomness;
+pub mod stake;
 pub mod state;
 
 use instructions::*;
+use state::{ConfigParams, GameParams, MapLayout};
 
 declare_id!("Hx7eQa2NhDDKiBThKyo4VNLnBi7pApQX9JZTsA5xBbdb");
 
@@ -17,14 +21,35 @@ pub mod deadmint {
     pub fn initialize_game(
         ctx: Context<InitializeGame>,
         game_id: u64,
-        entry_fee: u64,
-        max_players: u8,
+        params: GameParams,
     ) -> Result<()> {
-        instructions::initialize_game::handler(ctx, game_id, entry_fee, max_players)
+        instructions::initialize_game::handler(ctx, game_id, params)
     }
 
-    pub fn join_game(ctx: Context<JoinGame>, player_authority: Pubkey) -> Result<()> {
-        instructions::join_game::handler(ctx, player_authority)
+    /// Replace the lobby's arena with a hand-designed map before anyone joins.
+    /// Only the game authority can call it; the map is validated and its hash stored.
+    pub fn set_map(ctx: Context<SetMap>, layout: MapLayout) -> Result<()> {
+        instructions::set_map::handler(ctx, layout)
+    }
+
+    /// Join a lobby with a session key, contributing 32 bytes of entropy to the game seed.
+    pub fn join_game(
+        ctx: Context<JoinGame>,
+        player_authority: Pubkey,
+        entropy: [u8; 32],
+    ) -> Result<()> {
+        instructions::join_game::handler(ctx, player_authority, entropy)
+    }
+
+    /// Leave a lobby before it starts, refunding the wager to the paying wallet.
+    pub fn leave_lobby(ctx: Context<LeaveLobby>) -> Result<()> {
+        instructions::leave_lobby::handler(ctx)
+    }
+
+    /// Cancel a lobby that never filled, refunding every wager and closing all accounts.
+    /// Remaining accounts: `[player_pda, payer_wallet]` for each joined player.
+    pub fn cancel_game<'info>(ctx: Context<'_, '_, 'info, 'info, CancelGame<'info>>) -> Result<()> {
+        instructions::cancel_game::handler(ctx)
     }
 
     pub fn move_player(ctx: Context<MovePlayer>, direction: u8) -> Result<()> {
@@ -35,18 +60,68 @@ pub mod deadmint {
         instructions::place_bomb::handler(ctx)
     }
 
+    /// Detonate a bomb whose fuse has run out, along with every bomb it chains into.
+    /// Remaining accounts: every Player PDA of the game, in index order.
     pub fn detonate_bomb(ctx: Context<DetonateBomb>, bomb_index: u8) -> Result<()> {
         instructions::detonate_bomb::handler(ctx, bomb_index)
     }
 
+    /// End the game once one player (or none) is left or the round timer runs out.
+    /// Remaining accounts: every Player PDA of the game, in index order.
     pub fn check_game_end(ctx: Context<CheckGameEnd>) -> Result<()> {
         instructions::check_game_end::handler(ctx)
     }
 
+    /// Pay a placed player (winner, runner-up, tied survivors...) their share of the pool.
     pub fn claim_prize(ctx: Context<ClaimPrize>) -> Result<()> {
         instructions::claim_prize::handler(ctx)
     }
 
+    /// Pay out a player's collected loot once the game has finished.
+    pub fn claim_loot(ctx: Context<ClaimLoot>) -> Result<()> {
+        instructions::claim_loot::handler(ctx)
+    }
+
+    /// Close a Player account after settlement, returning rent to its payer.
+    pub fn close_player(ctx: Context<ClosePlayer>) -> Result<()> {
+        instructions::close_player::handler(ctx)
+    }
+
+    /// Close a settled Game account once all its Player accounts are closed.
+    pub fn close_game(ctx: Context<CloseGame>) -> Result<()> {
+        instructions::close_game::handler(ctx)
+    }
+
+    /// One-time setup of the treasury PDA that accrues platform fees, by the upgrade authority.
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        instructions::initialize_treasury::handler(ctx)
+    }
+
+    /// Config-admin-only creation of the treasury token account for an SPL mint.
+    pub fn initialize_treasury_vault(ctx: Context<InitializeTreasuryVault>) -> Result<()> {
+        instructions::initialize_treasury_vault::handler(ctx)
+    }
+
+    /// Config-admin-only withdrawal of accrued platform fees (SOL, or one SPL mint).
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_fees::handler(ctx, amount)
+    }
+
+    /// One-time setup of the protocol config PDA (fee cap, entry-fee range, pause switch), by the upgrade authority.
+    pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+        instructions::initialize_config::handler(ctx, params)
+    }
+
+    /// Admin-only update of the protocol config, including pausing and unpausing.
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        instructions::update_config::handler(ctx, params)
+    }
+
+    /// Hand the config admin role, treasury withdrawals included, to a new key (which must co-sign).
+    pub fn transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
+        instructions::transfer_admin::handler(ctx)
+    }
+
     /// Delegate a PDA (Game or Player) to the Ephemeral Rollup validator.
     /// Seeds are passed as instruction data so the SDK can verify PDA ownership.
     pub fn delegate(ctx: Context<DelegateInput>, seeds: Vec<Vec<u8>>) -> Result<()> {
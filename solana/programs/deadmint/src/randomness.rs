@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+use crate::errors::DeadmintError;
+use crate::state::*;
+
+// Randomness for block drops. Each game has a seed that is
+//   1. committed at creation:  H("deadmint-seed" || game || game_id || creator || slot)
+//   2. mixed with the 32 bytes of entropy every player contributes at join:
+//      seed = H(seed || entropy)
+//   3. fixed when the game starts by mixing in the latest SlotHashes entry.
+// A detonation then rolls its drops from H(seed || bomb || placed_at_slot ||
+// hash of the last slot before the bomb's fuse expiry) through the engine's
+// xoshiro256** PRNG. That slot hash did not exist when the bomb was placed, and
+// it is the same whenever the (permissionless) detonate call lands, so drops are
+// unpredictable in advance; every input is public, so they are auditable afterwards.
+// If the call comes so late that the slot has aged out of SlotHashes (~512 slots),
+// no slot hash is mixed in at all rather than whichever entry happens to be oldest.
+
+const SLOT_HASH_ENTRY: usize = 8 + 32;
+
+/// Seed committed at game creation.
+pub fn initial_seed(game: &Pubkey, game_id: u64, creator: &Pubkey, slot: u64) -> [u8; 32] {
+    hashv(&[
+        b"deadmint-seed".as_ref(),
+        game.as_ref(),
+        &game_id.to_le_bytes(),
+        creator.as_ref(),
+        &slot.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Mixes `part` into `seed`.
+pub fn mix(seed: &[u8; 32], part: &[u8]) -> [u8; 32] {
+    hashv(&[seed.as_ref(), part]).to_bytes()
+}
+
+/// PRNG seed for the drops of the chain set off by `bomb`.
+pub fn detonation_seed(game: &Game, slot_hashes: &AccountInfo, bomb_index: u8) -> Result<[u8; 32]> {
+    // An out-of-range index is rejected by the engine; the seed doesn't matter then
+    let Some(bomb) = game.bombs.get(bomb_index as usize) else {
+        return Ok([0; 32]);
+    };
+    let fuse_expiry = bomb.placed_at_slot + bomb.fuse_slots as u64;
+    let slot_hash = slot_hash_before(slot_hashes, fuse_expiry)?.unwrap_or([0; 32]);
+
+    Ok(hashv(&[
+        game.seed.as_ref(),
+        &[bomb_index],
+        &bomb.placed_at_slot.to_le_bytes(),
+        slot_hash.as_ref(),
+    ])
+    .to_bytes())
+}
+
+/// Hash of the most recent slot in the SlotHashes sysvar.
+pub fn latest_slot_hash(slot_hashes: &AccountInfo) -> Result<[u8; 32]> {
+    slot_hash_before(slot_hashes, u64::MAX)?.ok_or_else(|| error!(DeadmintError::InvalidSlotHashes))
+}
+
+/// Hash of the newest slot strictly before `slot` in the SlotHashes sysvar
+/// (skipped slots have no entry). Once the current slot reaches `slot` that
+/// entry never changes, until it ages out of the sysvar and `None` is returned.
+fn slot_hash_before(slot_hashes: &AccountInfo, slot: u64) -> Result<Option<[u8; 32]>> {
+    let data = slot_hashes.try_borrow_data()?;
+    let len = data
+        .get(..8)
+        .map(|b| u64::from_le_bytes(b.try_into().unwrap()) as usize)
+        .ok_or(DeadmintError::InvalidSlotHashes)?;
+    require!(
+        len > 0 && data.len() >= 8 + len * SLOT_HASH_ENTRY,
+        DeadmintError::InvalidSlotHashes
+    );
+
+    // Entries are (slot: u64, hash: [u8; 32]), newest first
+    let entry = |i: usize| {
+        let at = 8 + i * SLOT_HASH_ENTRY;
+        let entry_slot = u64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+        let hash: [u8; 32] = data[at + 8..at + SLOT_HASH_ENTRY].try_into().unwrap();
+        (entry_slot, hash)
+    };
+    // The oldest entry must predate `slot`, or the newest one before it may have aged out
+    if entry(len - 1).0 >= slot {
+        return Ok(None);
+    }
+    Ok((0..len).map(entry).find(|&(entry_slot, _)| entry_slot < slot).map(|(_, hash)| hash))
+}
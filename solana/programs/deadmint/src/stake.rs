@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::state::*;
+use crate::errors::DeadmintError;
+
+// Stake movement shared by every instruction that takes in or pays out wagers.
+// Native SOL games keep the stake as lamports on the Game PDA; SPL-token games
+// (`game.mint != Pubkey::default()`) keep it in the `[b"vault", game]` token
+// account owned by the Game PDA. Both the classic Token program and Token-2022
+// are supported through the token interface.
+
+/// Token accounts needed to move the stake of an SPL-token game.
+pub struct TokenStake<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+/// Resolves the optional token accounts of an instruction.
+/// Returns `None` for native SOL games, and errors if a token game is missing any.
+pub fn token_stake<'a, 'info>(
+    game: &Game,
+    mint: &'a Option<InterfaceAccount<'info, Mint>>,
+    vault: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+    token_program: &'a Option<Interface<'info, TokenInterface>>,
+) -> Result<Option<TokenStake<'a, 'info>>> {
+    if !game.is_token_game() {
+        return Ok(None);
+    }
+    match (mint, vault, token_program) {
+        (Some(mint), Some(vault), Some(token_program)) => {
+            require_keys_eq!(mint.key(), game.mint, DeadmintError::InvalidMint);
+            Ok(Some(TokenStake { mint, vault, token_program }))
+        }
+        _ => err!(DeadmintError::MissingTokenAccounts),
+    }
+}
+
+/// Moves `amount` from a wallet into the game. Returns the amount actually
+/// received, which can be lower for Token-2022 mints with a transfer fee.
+pub fn pay_in<'info>(
+    game: &Account<'info, Game>,
+    stake: Option<&TokenStake<'_, 'info>>,
+    from: &Signer<'info>,
+    from_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    match stake {
+        None => {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: from.to_account_info(),
+                        to: game.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+            Ok(amount)
+        }
+        Some(stake) => {
+            let from_token_account =
+                from_token_account.ok_or(DeadmintError::MissingTokenAccounts)?;
+            let before = stake.vault.amount;
+
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    stake.token_program.to_account_info(),
+                    TransferChecked {
+                        from: from_token_account.to_account_info(),
+                        mint: stake.mint.to_account_info(),
+                        to: stake.vault.to_account_info(),
+                        authority: from.to_account_info(),
+                    },
+                ),
+                amount,
+                stake.mint.decimals,
+            )?;
+
+            let after = vault_balance(stake)?;
+            Ok(after.checked_sub(before).ok_or(DeadmintError::MathOverflow)?)
+        }
+    }
+}
+
+/// Pays `amount` out of the game to `to_wallet`. For token games the payout
+/// goes to `to_token_account`, which must be owned by `to_wallet`.
+pub fn pay_out<'info>(
+    game: &Account<'info, Game>,
+    stake: Option<&TokenStake<'_, 'info>>,
+    to_wallet: &AccountInfo<'info>,
+    to_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    match stake {
+        None => {
+            let game_info = game.to_account_info();
+            **game_info.try_borrow_mut_lamports()? -= amount;
+            **to_wallet.try_borrow_mut_lamports()? += amount;
+        }
+        Some(stake) => {
+            let to_token_account =
+                to_token_account.ok_or(DeadmintError::MissingTokenAccounts)?;
+            require_keys_eq!(
+                to_token_account.owner,
+                to_wallet.key(),
+                DeadmintError::InvalidTokenAccount
+            );
+
+            let game_id = game.game_id.to_le_bytes();
+            let signer_seeds: &[&[&[u8]]] = &[&[b"game", game_id.as_ref(), &[game.bump]]];
+
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    stake.token_program.to_account_info(),
+                    TransferChecked {
+                        from: stake.vault.to_account_info(),
+                        mint: stake.mint.to_account_info(),
+                        to: to_token_account.to_account_info(),
+                        authority: game.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+                stake.mint.decimals,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Closes the vault of a token game, returning its rent to `destination`.
+/// Any balance left over (e.g. tokens sent directly to the vault) is swept to
+/// `destination_token_account` first, mirroring how `close = ...` sweeps lamports.
+pub fn close_vault<'info>(
+    game: &Account<'info, Game>,
+    stake: &TokenStake<'_, 'info>,
+    destination: &AccountInfo<'info>,
+    destination_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+) -> Result<()> {
+    let leftover = vault_balance(stake)?;
+    pay_out(game, Some(stake), destination, destination_token_account, leftover)?;
+
+    let game_id = game.game_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"game", game_id.as_ref(), &[game.bump]]];
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        stake.token_program.to_account_info(),
+        CloseAccount {
+            account: stake.vault.to_account_info(),
+            destination: destination.clone(),
+            authority: game.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+/// Current vault balance, re-read from account data so it reflects transfers
+/// made earlier in the same instruction.
+fn vault_balance(stake: &TokenStake) -> Result<u64> {
+    let vault_info = stake.vault.to_account_info();
+    let data = vault_info.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
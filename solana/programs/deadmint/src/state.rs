@@ -1,32 +1,26 @@
 use anchor_lang::prelude::*;
+use crate::errors::DeadmintError;
 
-// Cell types
-pub const CELL_EMPTY: u8 = 0;
-pub const CELL_WALL: u8 = 1;
-pub const CELL_BLOCK: u8 = 2;
-pub const CELL_BOMB: u8 = 3;
-pub const CELL_EXPLOSION: u8 = 4;
-pub const CELL_LOOT: u8 = 5;
-pub const CELL_POWERUP: u8 = 6;
-
-// Game status
-pub const STATUS_LOBBY: u8 = 0;
-pub const STATUS_ACTIVE: u8 = 1;
-pub const STATUS_FINISHED: u8 = 2;
-pub const STATUS_CLAIMED: u8 = 3;
-
-pub const GRID_WIDTH: u8 = 13;
-pub const GRID_HEIGHT: u8 = 11;
-pub const GRID_CELLS: usize = 143; // 13 × 11
-pub const MAX_BOMBS: usize = 12; // 4 players × 3 max bombs each
-pub const EXPLOSION_DURATION_SLOTS: u64 = 5; // ~2 seconds at 400ms slots
-
-pub const SPAWN_POSITIONS: [(u8, u8); 4] = [(1, 1), (11, 1), (1, 9), (11, 9)];
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+// Board rules and their constants live in the engine crate
+pub use deadmint_engine::{
+    CELL_BLOCK, CELL_BOMB, CELL_EMPTY, CELL_EXPLOSION, CELL_LOOT, CELL_POWERUP, CELL_WALL,
+    MAX_BOMBS, MAX_BOMBS_PER_PLAYER, MAX_GRID_CELLS, MAX_GRID_HEIGHT, MAX_GRID_WIDTH, MAX_PLAYERS,
+    MAX_SPEED, MIN_GRID_HEIGHT, MIN_GRID_WIDTH, STATUS_ACTIVE, STATUS_CLAIMED, STATUS_FINISHED,
+    STATUS_LOBBY,
+};
+use deadmint_engine::map::{self, MapOptions};
+use solana_sha256_hasher::hashv;
+use deadmint_engine::{Bomb, GameState, PlayerState, Rules};
+
+pub const LOBBY_EXPIRY_SECONDS: i64 = 3600; // unfilled lobbies can be cancelled by anyone after 1h
+pub const MAX_PAYOUT_PLACES: usize = 3; // payout table covers 1st..3rd
+pub const MAX_BOUNTY_BPS: u16 = 10_000; // bounty can be at most the victim's full wager
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000; // protocol cap: 10%
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
 pub struct BombSlot {
     pub active: bool,
-    pub owner: Pubkey,
+    pub owner_index: u8, // player_index of the player who placed it
     pub x: u8,
     pub y: u8,
     pub range: u8,
@@ -35,17 +29,162 @@ pub struct BombSlot {
     pub detonated: bool,
 }
 
-// BombSlot size: 1 + 32 + 1 + 1 + 1 + 1 + 8 + 1 = 46 bytes
+// BombSlot size: 1 + 1 + 1 + 1 + 1 + 1 + 8 + 1 = 15 bytes
+
+impl From<&BombSlot> for Bomb {
+    fn from(slot: &BombSlot) -> Self {
+        Bomb {
+            active: slot.active,
+            owner_index: slot.owner_index,
+            x: slot.x,
+            y: slot.y,
+            range: slot.range,
+            fuse_slots: slot.fuse_slots,
+            placed_at_slot: slot.placed_at_slot,
+            detonated: slot.detonated,
+        }
+    }
+}
+
+impl From<&Bomb> for BombSlot {
+    fn from(bomb: &Bomb) -> Self {
+        BombSlot {
+            active: bomb.active,
+            owner_index: bomb.owner_index,
+            x: bomb.x,
+            y: bomb.y,
+            range: bomb.range,
+            fuse_slots: bomb.fuse_slots,
+            placed_at_slot: bomb.placed_at_slot,
+            detonated: bomb.detonated,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq)]
+pub struct SpawnPoint {
+    pub x: u8,
+    pub y: u8,
+}
+
+/// A hand-designed arena uploaded with `set_map`. `cells` and `powerup_types`
+/// are row-major over the game's grid; `spawns` has one entry per player index.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MapLayout {
+    pub cells: Vec<u8>,
+    pub powerup_types: Vec<u8>,
+    pub spawns: Vec<SpawnPoint>,
+}
+
+/// Creator-chosen settings for a game, validated by `initialize_game` and
+/// copied onto the Game account, where every instruction reads them.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct GameParams {
+    pub entry_fee: u64,
+    pub max_players: u8,
+    pub grid_width: u8, // odd, MIN_GRID_WIDTH..=MAX_GRID_WIDTH
+    pub grid_height: u8, // odd, MIN_GRID_HEIGHT..=MAX_GRID_HEIGHT
+    pub round_duration: u16, // seconds, 0 = no time limit
+    pub fuse_slots: u8, // bomb fuse, e.g. 8 = ~3 seconds at ~400ms slots
+    pub explosion_duration_slots: u8, // e.g. 5 = ~2 seconds
+    pub start_bomb_range: u8,
+    pub start_max_bombs: u8,
+    pub start_speed: u8,
+    pub bomb_range_cap: u8, // powerup caps
+    pub max_bombs_cap: u8,
+    pub speed_cap: u8,
+    pub platform_fee_bps: u16, // at most MAX_PLATFORM_FEE_BPS
+    pub payout_bps: [u16; MAX_PAYOUT_PLACES], // e.g. [10_000, 0, 0] or [6_000, 3_000, 1_000]
+    pub bounty_bps: u16, // 0 = bounty mode off
+    pub map: MapParams,
+}
+
+/// How `initialize_game` generates the arena, seeded from the game seed.
+/// See `deadmint_engine::map` for the templates and symmetry modes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MapParams {
+    pub template: u8, // 0 = classic, 1 = open, 2 = staggered pillars
+    pub block_density: u8, // % of open cells outside the spawn zones that get a block
+    pub loot_density: u8, // % of the cells left open that start with loot
+    pub powerup_density: u8, // % of the cells left open that start with a powerup
+    pub symmetry: u8, // 0 = none, 1 = left/right mirror, 2 = mirrored across both axes
+}
+
+impl From<&MapParams> for MapOptions {
+    fn from(params: &MapParams) -> Self {
+        MapOptions {
+            template: params.template,
+            block_density: params.block_density,
+            loot_density: params.loot_density,
+            powerup_density: params.powerup_density,
+            symmetry: params.symmetry,
+        }
+    }
+}
+
+impl GameParams {
+    /// Number of grid cells.
+    pub fn grid_cells(&self) -> usize {
+        (self.grid_width as usize) * (self.grid_height as usize)
+    }
+
+    /// Bomb slots needed for every player to have `max_bombs_cap` bombs out at once.
+    pub fn bomb_slots(&self) -> usize {
+        (self.max_players as usize) * (self.max_bombs_cap as usize)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_players >= 2 && self.max_players as usize <= MAX_PLAYERS,
+            DeadmintError::InvalidMaxPlayers
+        );
+        map::validate_dimensions(self.grid_width, self.grid_height).map_err(DeadmintError::from)?;
+        require!(
+            self.fuse_slots > 0 && self.explosion_duration_slots > 0,
+            DeadmintError::InvalidGameParams
+        );
+        require!(
+            self.start_bomb_range >= 1
+                && self.start_bomb_range <= self.bomb_range_cap
+                && self.bomb_range_cap < self.grid_width.max(self.grid_height),
+            DeadmintError::InvalidGameParams
+        );
+        require!(
+            self.start_max_bombs >= 1
+                && self.start_max_bombs <= self.max_bombs_cap
+                && self.max_bombs_cap <= MAX_BOMBS_PER_PLAYER,
+            DeadmintError::InvalidGameParams
+        );
+        require!(
+            self.start_speed >= 1 && self.start_speed <= self.speed_cap && self.speed_cap <= MAX_SPEED,
+            DeadmintError::InvalidGameParams
+        );
+        require!(
+            self.platform_fee_bps <= MAX_PLATFORM_FEE_BPS,
+            DeadmintError::FeeTooHigh
+        );
+        require!(
+            self.payout_bps.iter().map(|&b| b as u32).sum::<u32>() == 10_000
+                && self.payout_bps.windows(2).all(|w| w[0] >= w[1]),
+            DeadmintError::InvalidPayoutTable
+        );
+        require!(self.bounty_bps <= MAX_BOUNTY_BPS, DeadmintError::InvalidBounty);
+        MapOptions::from(&self.map).validate().map_err(DeadmintError::from)?;
+        Ok(())
+    }
+}
 
 #[account]
+#[derive(InitSpace)]
 pub struct Game {
     pub game_id: u64,
-    pub authority: Pubkey,
+    pub authority: Pubkey, // creator — paid the Game account rent
     pub grid_width: u8,
     pub grid_height: u8,
     pub max_players: u8,
     pub current_players: u8,
     pub entry_fee: u64,
+    pub mint: Pubkey, // Pubkey::default() means native SOL, otherwise stake is held in the vault
     pub prize_pool: u64,
     pub status: u8,
     pub winner: Pubkey, // Pubkey::default() means no winner
@@ -53,32 +192,165 @@ pub struct Game {
     pub started_at: i64,
     pub round_duration: u16,
     pub platform_fee_bps: u16,
+    pub bounty_bps: u16, // share of a victim's wager credited to the killer (0 = off)
+    pub fuse_slots: u8,
+    pub explosion_duration_slots: u8,
+    pub start_bomb_range: u8,
+    pub start_max_bombs: u8,
+    pub start_speed: u8,
+    pub bomb_range_cap: u8,
+    pub max_bombs_cap: u8,
+    pub speed_cap: u8,
     pub bump: u8,
-    // Grid data (embedded)
-    pub cells: [u8; GRID_CELLS],
-    pub powerup_types: [u8; GRID_CELLS],
+    // Grid data (embedded), grid_width × grid_height cells, row-major
+    #[max_len(MAX_GRID_CELLS)]
+    pub cells: Vec<u8>,
+    #[max_len(MAX_GRID_CELLS)]
+    pub powerup_types: Vec<u8>,
     // Bombs (embedded — no separate accounts!)
-    pub bombs: [BombSlot; MAX_BOMBS],
+    #[max_len(MAX_BOMBS)]
+    pub bombs: Vec<BombSlot>, // max_players × max_bombs_cap slots
     pub bomb_count: u8,
-    pub last_detonate_slot: u64,
+    pub start_slot: u64, // base-layer slot the game went active
+    // Absolute slots, so expiry works on whichever clock the game runs on (the
+    // rollup has its own slot counter, unrelated to start_slot)
+    #[max_len(MAX_GRID_CELLS)]
+    pub explosion_expiry: Vec<u64>, // per cell: last live slot of a CELL_EXPLOSION
+    pub closed_players: u8, // Player accounts closed after settlement
+    // Placement payouts
+    pub payout_bps: [u16; MAX_PAYOUT_PLACES], // share of the pool per place, sums to 10_000
+    pub placements: [u8; MAX_PLAYERS], // by player_index: 1 = first, 0 = unplaced; ties share a place
+    pub claimed_mask: u8, // bit i set = player index i has claimed its prize
+    pub payout_pool: u64, // pool net of platform fee, fixed when the game ends
+    pub platform_fee_due: u64, // sent to the treasury by the first claim
+    pub seed: [u8; 32], // randomness seed, see `randomness` — fixed once the game starts
+    pub spawns: [SpawnPoint; MAX_PLAYERS], // by player_index
+    pub map_hash: [u8; 32], // sha256 of the validated grid and spawns, see `seal_map`
 }
 
 impl Game {
-    // 8 (discriminator) + 8 + 32 + 1 + 1 + 1 + 1 + 8 + 8 + 1 + 32 + 8 + 8 + 2 + 2 + 1
-    // + 143 + 143 + (46 * 12) + 1 + 8 = 1112
-    pub const SIZE: usize = 8 + 8 + 32 + 1 + 1 + 1 + 1 + 8 + 8 + 1 + 32 + 8 + 8 + 2 + 2 + 1
-        + GRID_CELLS + GRID_CELLS + (46 * MAX_BOMBS) + 1 + 8;
+    // 8 (discriminator) + 8 + 32 + 1 + 1 + 1 + 1 + 8 + 32 + 8 + 1 + 32 + 8 + 8 + 2 + 2 + 2 + 8 + 1
+    // + (4 + cells) + (4 + cells) + (4 + 15 * bombs) + 1 + 8 + (4 + 8 * cells) + 1 + (2 * 3) + 8
+    // + 1 + 8 + 8 + 32 + (2 * 8) + 32 = 301 + 10 * cells + 15 * bombs,
+    // e.g. 1821 for a 13×11 grid with 2 players × 3 bombs
+    /// Account size for a game with `cells` grid cells and `bombs` bomb slots.
+    pub const fn space(cells: usize, bombs: usize) -> usize {
+        8 + 8 + 32 + 1 + 1 + 1 + 1 + 8 + 32 + 8 + 1 + 32 + 8 + 8 + 2 + 2 + 2 + 8 + 1
+            + (4 + cells) + (4 + cells) + (4 + 15 * bombs) + 1 + 8 + (4 + 8 * cells) + 1
+            + (2 * MAX_PAYOUT_PLACES) + MAX_PLAYERS + 1 + 8 + 8 + 32
+            + (2 * MAX_PLAYERS) + 32
+    }
+
+    /// Size of the largest possible game (biggest grid, most bomb slots).
+    pub const SIZE: usize = Self::space(MAX_GRID_CELLS, MAX_BOMBS);
+
+    /// Spawn positions of the player indices in use.
+    pub fn spawn_positions(&self) -> Vec<(u8, u8)> {
+        self.spawns[..self.max_players as usize]
+            .iter()
+            .map(|s| (s.x, s.y))
+            .collect()
+    }
+
+    /// Validates the grid and spawns, then records their hash so anyone can
+    /// check which arena a game was played on. Called whenever the map changes.
+    pub fn seal_map(&mut self) -> Result<()> {
+        let spawns = self.spawn_positions();
+        map::validate(&self.cells, &self.powerup_types, self.grid_width, self.grid_height, &spawns)
+            .map_err(DeadmintError::from)?;
+
+        let spawn_bytes: Vec<u8> = spawns.iter().flat_map(|&(x, y)| [x, y]).collect();
+        self.map_hash = hashv(&[
+            &[self.grid_width, self.grid_height],
+            &self.cells,
+            &self.powerup_types,
+            &spawn_bytes,
+        ])
+        .to_bytes();
+        Ok(())
+    }
+
+    /// Copies the rule-relevant part of the game into an engine state.
+    pub fn to_state(&self) -> GameState {
+        GameState {
+            status: self.status,
+            grid_width: self.grid_width,
+            grid_height: self.grid_height,
+            cells: self.cells.clone(),
+            powerup_types: self.powerup_types.clone(),
+            explosion_expiry: self.explosion_expiry.clone(),
+            bombs: self.bombs.iter().map(Bomb::from).collect(),
+            bomb_count: self.bomb_count,
+            start_slot: self.start_slot,
+            started_at: self.started_at,
+            round_duration: self.round_duration,
+            prize_pool: self.prize_pool,
+            winner: self.winner.to_bytes(),
+            placements: self.placements,
+            rules: Rules {
+                fuse_slots: self.fuse_slots,
+                explosion_duration_slots: self.explosion_duration_slots,
+                bomb_range_cap: self.bomb_range_cap,
+                max_bombs_cap: self.max_bombs_cap,
+                speed_cap: self.speed_cap,
+                bounty_bps: self.bounty_bps,
+            },
+        }
+    }
+
+    /// Writes an engine state produced by `to_state` back. Rules are fixed at
+    /// creation and are not written back.
+    pub fn apply_state(&mut self, state: &GameState) {
+        self.status = state.status;
+        self.cells.clone_from(&state.cells);
+        self.powerup_types.clone_from(&state.powerup_types);
+        self.explosion_expiry.clone_from(&state.explosion_expiry);
+        self.bombs = state.bombs.iter().map(BombSlot::from).collect();
+        self.bomb_count = state.bomb_count;
+        self.prize_pool = state.prize_pool;
+        self.winner = Pubkey::new_from_array(state.winner);
+        self.placements = state.placements;
+    }
+
+    /// Whether wagers are an SPL token (held in the vault) rather than native SOL.
+    pub fn is_token_game(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
 
-    pub fn cell_idx(&self, x: u8, y: u8) -> usize {
-        (y as usize) * (self.grid_width as usize) + (x as usize)
+    /// Prize owed to a player from `payout_pool`, based on its placement.
+    /// Players tied on a place split the table shares of every place they
+    /// jointly occupy, e.g. two players tied for 2nd in a 60/30/10 game each
+    /// get (30 + 10) / 2. Places the lobby is too small to fill roll up into 1st.
+    pub fn placement_share(&self, player_index: usize) -> u64 {
+        let players = self.current_players as usize;
+        let place = self.placements[player_index] as usize;
+        if place == 0 {
+            return 0;
+        }
+
+        let tied = self.placements[..players]
+            .iter()
+            .filter(|&&p| p as usize == place)
+            .count();
+        let first = (place - 1).min(MAX_PAYOUT_PLACES);
+        let last = (place - 1 + tied).min(MAX_PAYOUT_PLACES);
+        let mut bps: u64 = self.payout_bps[first..last].iter().map(|&b| b as u64).sum();
+        if place == 1 && players < MAX_PAYOUT_PLACES {
+            bps += self.payout_bps[players..].iter().map(|&b| b as u64).sum::<u64>();
+        }
+
+        (self.payout_pool as u128 * bps as u128 / 10_000 / tied as u128) as u64
     }
 
-    pub fn find_free_bomb_slot(&self) -> Option<usize> {
-        self.bombs.iter().position(|b| !b.active)
+    /// Whether every player with a non-zero prize has claimed it.
+    pub fn all_prizes_claimed(&self) -> bool {
+        (0..self.current_players as usize)
+            .all(|i| self.claimed_mask & (1 << i) != 0 || self.placement_share(i) == 0)
     }
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Player {
     pub game: Pubkey,
     pub authority: Pubkey, // session key pubkey
@@ -86,7 +358,7 @@ pub struct Player {
     pub x: u8,
     pub y: u8,
     pub alive: bool,
-    pub collected_sol: u64,
+    pub collected_sol: u64, // loot + bounty balance, already deducted from game.prize_pool
     pub wager: u64,
     pub bomb_range: u8,
     pub max_bombs: u8,
@@ -95,10 +367,156 @@ pub struct Player {
     pub last_move_slot: u64,
     pub kills: u8,
     pub input_nonce: u64,
+    pub eliminated_at_slot: u64, // 0 while alive
+    pub payer: Pubkey, // wallet that paid the wager and rent — receives every payout
     pub bump: u8,
 }
 
 impl Player {
-    // 8 (discriminator) + 32 + 32 + 1 + 1 + 1 + 1 + 8 + 8 + 1 + 1 + 1 + 1 + 8 + 1 + 8 + 1 = 114
-    pub const SIZE: usize = 8 + 32 + 32 + 1 + 1 + 1 + 1 + 8 + 8 + 1 + 1 + 1 + 1 + 8 + 1 + 8 + 1;
+    // 8 (discriminator) + 32 + 32 + 1 + 1 + 1 + 1 + 8 + 8 + 1 + 1 + 1 + 1 + 8 + 1 + 8 + 8 + 32 + 1 = 154
+    pub const SIZE: usize =
+        8 + 32 + 32 + 1 + 1 + 1 + 1 + 8 + 8 + 1 + 1 + 1 + 1 + 8 + 1 + 8 + 8 + 32 + 1;
+
+    /// Loads the game's complete Player set from `accounts`, which must be exactly
+    /// `current_players` accounts in index order, each the `[b"player", game, index]`
+    /// PDA of this program.
+    pub fn load_set(game: &Pubkey, current_players: u8, accounts: &[AccountInfo]) -> Result<Vec<Player>> {
+        require!(
+            accounts.len() == current_players as usize,
+            DeadmintError::IncompletePlayerSet
+        );
+
+        let mut players = Vec::with_capacity(accounts.len());
+        for (i, acc_info) in accounts.iter().enumerate() {
+            let player = Player::load(acc_info).map_err(|_| DeadmintError::IncompletePlayerSet)?;
+            let expected = Pubkey::create_program_address(
+                &[b"player", game.as_ref(), &[i as u8], &[player.bump]],
+                &crate::ID,
+            )
+            .map_err(|_| DeadmintError::IncompletePlayerSet)?;
+            require_keys_eq!(acc_info.key(), expected, DeadmintError::IncompletePlayerSet);
+            players.push(player);
+        }
+
+        Ok(players)
+    }
+
+    pub fn to_state(&self) -> PlayerState {
+        PlayerState {
+            authority: self.authority.to_bytes(),
+            index: self.player_index,
+            x: self.x,
+            y: self.y,
+            alive: self.alive,
+            collected: self.collected_sol,
+            wager: self.wager,
+            bomb_range: self.bomb_range,
+            max_bombs: self.max_bombs,
+            active_bombs: self.active_bombs,
+            speed: self.speed,
+            last_move_slot: self.last_move_slot,
+            kills: self.kills,
+            input_nonce: self.input_nonce,
+            eliminated_at_slot: self.eliminated_at_slot,
+        }
+    }
+
+    /// Writes an engine state produced by `to_state` back. Identity fields
+    /// (authority, index, wager) never change during play and are not written back.
+    pub fn apply_state(&mut self, state: &PlayerState) {
+        self.x = state.x;
+        self.y = state.y;
+        self.alive = state.alive;
+        self.collected_sol = state.collected;
+        self.bomb_range = state.bomb_range;
+        self.max_bombs = state.max_bombs;
+        self.active_bombs = state.active_bombs;
+        self.speed = state.speed;
+        self.last_move_slot = state.last_move_slot;
+        self.kills = state.kills;
+        self.input_nonce = state.input_nonce;
+        self.eliminated_at_slot = state.eliminated_at_slot;
+    }
+
+    /// Reads a Player from a raw (e.g. remaining) account, checking owner and discriminator.
+    pub fn load(info: &AccountInfo) -> Result<Player> {
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let data = info.try_borrow_data()?;
+        Player::try_deserialize(&mut &data[..])
+    }
+
+    /// Writes a Player read with `load` back into its account.
+    pub fn store(&self, info: &AccountInfo) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        self.try_serialize(&mut writer)
+    }
 }
+
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    // Withdrawals are gated on `Config.admin`, so `transfer_admin` moves them too.
+    // Lamport totals; token fees accrue in `[b"treasury_vault", mint]` accounts
+    pub total_fees_collected: u64,
+    pub total_fees_withdrawn: u64,
+    pub bump: u8,
+}
+
+impl Treasury {
+    // 8 (discriminator) + 8 + 8 + 1 = 25
+    pub const SIZE: usize = 8 + 8 + 8 + 1;
+}
+
+/// Admin-set protocol limits, validated by `initialize_config` and `update_config`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ConfigParams {
+    pub max_platform_fee_bps: u16, // at most MAX_PLATFORM_FEE_BPS
+    pub min_entry_fee: u64, // lamports, SOL games only
+    pub max_entry_fee: u64, // lamports, SOL games only
+    pub paused: bool,
+}
+
+impl ConfigParams {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_platform_fee_bps <= MAX_PLATFORM_FEE_BPS,
+            DeadmintError::FeeTooHigh
+        );
+        require!(
+            self.min_entry_fee <= self.max_entry_fee,
+            DeadmintError::InvalidConfig
+        );
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub admin: Pubkey, // only key allowed to update the config or hand it over
+    pub max_platform_fee_bps: u16,
+    pub min_entry_fee: u64, // lamports; token games aren't range-checked
+    pub max_entry_fee: u64,
+    pub paused: bool, // halts new games, joins and gameplay; exits stay open
+    pub bump: u8,
+}
+
+impl Config {
+    // 8 (discriminator) + 32 + 2 + 8 + 8 + 1 + 1 = 60
+    pub const SIZE: usize = 8 + 32 + 2 + 8 + 8 + 1 + 1;
+
+    pub fn apply(&mut self, params: &ConfigParams) {
+        self.max_platform_fee_bps = params.max_platform_fee_bps;
+        self.min_entry_fee = params.min_entry_fee;
+        self.max_entry_fee = params.max_entry_fee;
+        self.paused = params.paused;
+    }
+}
+
+// Hand-computed sizes must match the derived layout, so a field added to an
+// account without updating its SIZE fails to compile.
+const _: () = assert!(Game::SIZE == 8 + Game::INIT_SPACE);
+const _: () = assert!(Player::SIZE == 8 + Player::INIT_SPACE);
+const _: () = assert!(Treasury::SIZE == 8 + Treasury::INIT_SPACE);
+const _: () = assert!(Config::SIZE == 8 + Config::INIT_SPACE);
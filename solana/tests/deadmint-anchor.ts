@@ -5,7 +5,18 @@ import {
   PublicKey,
   SystemProgram,
   LAMPORTS_PER_SOL,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+  BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
 } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { randomBytes } from "crypto";
 import { expect } from "chai";
 import { Deadmint } from "../target/types/deadmint";
 
@@ -29,6 +40,26 @@ describe("deadmint", () => {
   const gameId = new anchor.BN(Date.now());
   const entryFee = new anchor.BN(0.05 * LAMPORTS_PER_SOL);
   const maxPlayers = 2;
+  const gameParams = {
+    entryFee,
+    maxPlayers,
+    gridWidth: 13,
+    gridHeight: 11,
+    roundDuration: 0, // no time limit
+    fuseSlots: 8, // ~3 seconds
+    explosionDurationSlots: 5, // ~2 seconds
+    startBombRange: 1,
+    startMaxBombs: 1,
+    startSpeed: 1,
+    bombRangeCap: 5,
+    maxBombsCap: 3,
+    speedCap: 3,
+    platformFeeBps: 300,
+    payoutBps: [10_000, 0, 0], // winner takes all
+    bountyBps: 0, // bounty mode off
+    // Classic arena: pillars at even x,y and a block on every other cell
+    map: { template: 0, blockDensity: 100, lootDensity: 0, powerupDensity: 0, symmetry: 0 },
+  };
 
   // Session keys
   const sessionKey1 = Keypair.generate();
@@ -36,16 +67,146 @@ describe("deadmint", () => {
   const player2Wallet = Keypair.generate();
 
   // PDAs
+  let treasuryPda: PublicKey;
+  let configPda: PublicKey;
+  let programDataPda: PublicKey;
   let gamePda: PublicKey;
   let player0Pda: PublicKey;
   let player1Pda: PublicKey;
 
+  const gamePdaFor = (id: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+  const playerPdaFor = (game: PublicKey, index: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("player"), game.toBuffer(), Buffer.from([index])],
+      program.programId
+    )[0];
+
+  // Creates a SOL game, uploads `layout` if given, and has each wallet join it in
+  // turn with its own key as the session key (the game starts once the lobby is
+  // full). Returns the game PDA and the Player PDAs in index order.
+  async function startGame(
+    id: anchor.BN,
+    params: typeof gameParams,
+    wallets: Keypair[],
+    layout?: { cells: number[]; powerupTypes: number[]; spawns: { x: number; y: number }[] }
+  ) {
+    const game = gamePdaFor(id);
+    await program.methods
+      .initializeGame(id, params)
+      .accounts({
+        game,
+        config: configPda,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    if (layout) {
+      await program.methods
+        .setMap(layout)
+        .accounts({ game, config: configPda, authority: payer.publicKey })
+        .rpc();
+    }
+
+    const players: PublicKey[] = [];
+    for (const [index, wallet] of wallets.entries()) {
+      const player = playerPdaFor(game, index);
+      await program.methods
+        .joinGame(wallet.publicKey, Array.from(randomBytes(32)))
+        .accounts({
+          game,
+          config: configPda,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          player,
+          payer: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+      players.push(player);
+    }
+    return { game, players };
+  }
+
+  // Open protocol limits every test runs under; updateConfig applies changes on top
+  const openLimits = {
+    maxPlatformFeeBps: 1_000,
+    minEntryFee: new anchor.BN(0),
+    maxEntryFee: new anchor.BN(100 * LAMPORTS_PER_SOL),
+    paused: false,
+  };
+  const updateConfig = (changes: Partial<typeof openLimits>) =>
+    program.methods
+      .updateConfig({ ...openLimits, ...changes })
+      .accounts({ config: configPda, admin: payer.publicKey })
+      .rpc();
+
+  const expectError = async (call: Promise<unknown>, code: string) => {
+    try {
+      await call;
+      expect.fail("Should have thrown");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code || e.message).to.include(code);
+    }
+  };
+
+  const checkGameEnd = (game: PublicKey, players: PublicKey[]) =>
+    program.methods
+      .checkGameEnd()
+      .accounts({ game, authority: payer.publicKey })
+      .remainingAccounts(players.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+
   before(async () => {
+    [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury")],
+      program.programId
+    );
+    [configPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      program.programId
+    );
+    // Setup instructions are gated on the upgrade authority stored here
+    [programDataPda] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+    );
     [gamePda] = PublicKey.findProgramAddressSync(
       [Buffer.from("game"), gameId.toArrayLike(Buffer, "le", 8)],
       program.programId
     );
 
+    // Treasury is a global singleton — only create it on a fresh validator
+    if (!(await connection.getAccountInfo(treasuryPda))) {
+      await program.methods
+        .initializeTreasury()
+        .accounts({
+          treasury: treasuryPda,
+          admin: payer.publicKey,
+          program: program.programId,
+          programData: programDataPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    // Config is a global singleton too — open limits, not paused
+    if (!(await connection.getAccountInfo(configPda))) {
+      await program.methods
+        .initializeConfig(openLimits)
+        .accounts({
+          config: configPda,
+          admin: payer.publicKey,
+          program: program.programId,
+          programData: programDataPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
     // Airdrop
     const sig = await connection.requestAirdrop(player2Wallet.publicKey, 2 * LAMPORTS_PER_SOL);
     await connection.confirmTransaction(sig);
@@ -55,11 +216,47 @@ describe("deadmint", () => {
     }
   });
 
+  it("rejects games outside the config limits, and new games while paused", async () => {
+    const limitId = new anchor.BN(Date.now() + 10);
+    const [limitPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), limitId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const initLimited = (params: typeof gameParams) =>
+      program.methods
+        .initializeGame(limitId, params)
+        .accounts({
+          game: limitPda,
+          config: configPda,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    // The config can cap the platform fee below the 10% protocol cap:
+    // 7% passes GameParams validation but not a 5% config cap
+    await updateConfig({ maxPlatformFeeBps: 500 });
+    await expectError(initLimited({ ...gameParams, platformFeeBps: 700 }), "FeeTooHigh");
+    await updateConfig({});
+    // ...and bounds the entry fee of SOL games, here at 100 SOL
+    await expectError(
+      initLimited({ ...gameParams, entryFee: new anchor.BN(101 * LAMPORTS_PER_SOL) }),
+      "EntryFeeOutOfRange"
+    );
+
+    await updateConfig({ paused: true });
+    await expectError(initLimited(gameParams), "ProtocolPaused");
+    await updateConfig({});
+
+    expect(await connection.getAccountInfo(limitPda)).to.equal(null);
+  });
+
   it("initializes a game", async () => {
     await program.methods
-      .initializeGame(gameId, entryFee, maxPlayers)
+      .initializeGame(gameId, gameParams)
       .accounts({
         game: gamePda,
+        config: configPda,
         payer: payer.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -91,9 +288,11 @@ describe("deadmint", () => {
     );
 
     await program.methods
-      .joinGame(sessionKey1.publicKey)
+      .joinGame(sessionKey1.publicKey, Array.from(randomBytes(32)))
       .accounts({
         game: gamePda,
+        config: configPda,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
         player: player0Pda,
         payer: payer.publicKey,
         systemProgram: SystemProgram.programId,
@@ -113,15 +312,19 @@ describe("deadmint", () => {
   });
 
   it("player 2 joins and game auto-starts", async () => {
+    const lobbySeed = (await program.account.game.fetch(gamePda)).seed;
+
     [player1Pda] = PublicKey.findProgramAddressSync(
       [Buffer.from("player"), gamePda.toBuffer(), Buffer.from([1])],
       program.programId
     );
 
     await program.methods
-      .joinGame(sessionKey2.publicKey)
+      .joinGame(sessionKey2.publicKey, Array.from(randomBytes(32)))
       .accounts({
         game: gamePda,
+        config: configPda,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
         player: player1Pda,
         payer: player2Wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -137,14 +340,77 @@ describe("deadmint", () => {
     const game = await program.account.game.fetch(gamePda);
     expect(game.currentPlayers).to.equal(2);
     expect(game.status).to.equal(1); // Active!
+    // The seed is re-mixed with player 2's entropy and a slot hash at start
+    expect(Buffer.from(game.seed).equals(Buffer.from(lobbySeed))).to.equal(false);
     expect(game.prizePool.toString()).to.equal(entryFee.mul(new anchor.BN(2)).toString());
   });
 
+  it("rejects joins and gameplay while paused, but keeps game-end checks open", async () => {
+    const asPlayer0 = {
+      game: gamePda,
+      config: configPda,
+      player: player0Pda,
+      authority: sessionKey1.publicKey,
+    };
+    await updateConfig({ paused: true });
+    try {
+      await expectError(
+        program.methods
+          .joinGame(payer.publicKey, Array.from(randomBytes(32)))
+          .accounts({
+            game: gamePda,
+            config: configPda,
+            slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+            player: playerPdaFor(gamePda, 2),
+            payer: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(),
+        "ProtocolPaused"
+      );
+      await expectError(
+        program.methods.movePlayer(1).accounts(asPlayer0).signers([sessionKey1]).rpc(),
+        "ProtocolPaused"
+      );
+      await expectError(
+        program.methods.placeBomb().accounts(asPlayer0).signers([sessionKey1]).rpc(),
+        "ProtocolPaused"
+      );
+      await expectError(
+        program.methods
+          .detonateBomb(0)
+          .accounts({
+            game: gamePda,
+            config: configPda,
+            slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+            authority: payer.publicKey,
+          })
+          .remainingAccounts(
+            [player0Pda, player1Pda].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+          )
+          .rpc(),
+        "ProtocolPaused"
+      );
+
+      // Exits don't read the pause flag: with both players alive this is a no-op
+      await checkGameEnd(gamePda, [player0Pda, player1Pda]);
+    } finally {
+      await updateConfig({});
+    }
+
+    const game = await program.account.game.fetch(gamePda);
+    expect(game.status).to.equal(1); // Still active
+    expect(game.bombCount).to.equal(0);
+    const player = await program.account.player.fetch(player0Pda);
+    expect([player.x, player.y]).to.deep.equal([1, 1]);
+  });
+
   it("player 1 moves down (session key, no popup)", async () => {
     await program.methods
       .movePlayer(1) // Down → (1,2)
       .accounts({
         game: gamePda,
+        config: configPda,
         player: player0Pda,
         authority: sessionKey1.publicKey,
       })
@@ -164,6 +430,7 @@ describe("deadmint", () => {
         .movePlayer(2) // Left → (0,2) which is a wall
         .accounts({
           game: gamePda,
+          config: configPda,
           player: player0Pda,
           authority: sessionKey1.publicKey,
         })
@@ -183,6 +450,7 @@ describe("deadmint", () => {
       .movePlayer(1) // Down → (1,3)
       .accounts({
         game: gamePda,
+        config: configPda,
         player: player0Pda,
         authority: sessionKey1.publicKey,
       })
@@ -194,6 +462,7 @@ describe("deadmint", () => {
       .placeBomb()
       .accounts({
         game: gamePda,
+        config: configPda,
         player: player0Pda,
         authority: sessionKey1.publicKey,
       })
@@ -216,6 +485,7 @@ describe("deadmint", () => {
       .movePlayer(0) // Up → (1,2)
       .accounts({
         game: gamePda,
+        config: configPda,
         player: player0Pda,
         authority: sessionKey1.publicKey,
       })
@@ -227,6 +497,7 @@ describe("deadmint", () => {
         .placeBomb()
         .accounts({
           game: gamePda,
+          config: configPda,
           player: player0Pda,
           authority: sessionKey1.publicKey,
         })
@@ -244,6 +515,8 @@ describe("deadmint", () => {
         .detonateBomb(0)
         .accounts({
           game: gamePda,
+          config: configPda,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
           authority: sessionKey1.publicKey,
         })
         .remainingAccounts([
@@ -271,10 +544,32 @@ describe("deadmint", () => {
       currentSlot = await connection.getSlot();
     }
 
+    // A crank can't leave a player out of kill resolution
+    try {
+      await program.methods
+        .detonateBomb(0)
+        .accounts({
+          game: gamePda,
+          config: configPda,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          authority: sessionKey1.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: player1Pda, isSigner: false, isWritable: true },
+        ])
+        .signers([sessionKey1])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code || e.message).to.include("IncompletePlayerSet");
+    }
+
     await program.methods
       .detonateBomb(0)
       .accounts({
         game: gamePda,
+        config: configPda,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
         authority: sessionKey1.publicKey,
       })
       .remainingAccounts([
@@ -292,10 +587,30 @@ describe("deadmint", () => {
     expect(p1.activeBombs).to.equal(0);
     // Player1 at (1,2) was hit by explosion from (1,3) range 1 going up
     expect(p1.alive).to.equal(false);
+    expect(p1.kills).to.equal(0); // self-kills are not credited
     console.log("  ✓ On-chain kill detection: player1 killed by own bomb!");
   });
 
+  it("check_game_end rejects a missing, duplicated or out-of-order Player set", async () => {
+    for (const players of [
+      [player1Pda], // player 0 left out
+      [player1Pda, player1Pda], // player 1 passed twice
+      [player1Pda, player0Pda], // both, but not in index order
+    ]) {
+      await expectError(checkGameEnd(gamePda, players), "IncompletePlayerSet");
+    }
+
+    // Nothing was decided from a partial set
+    const game = await program.account.game.fetch(gamePda);
+    expect(game.status).to.equal(1); // Still active
+  });
+
   it("check_game_end detects last player standing", async () => {
+    let ended: any = null;
+    const listener = program.addEventListener("gameEnded", (event) => {
+      ended = event;
+    });
+
     await program.methods
       .checkGameEnd()
       .accounts({
@@ -303,8 +618,8 @@ describe("deadmint", () => {
         authority: sessionKey2.publicKey,
       })
       .remainingAccounts([
-        { pubkey: player0Pda, isSigner: false, isWritable: false },
-        { pubkey: player1Pda, isSigner: false, isWritable: false },
+        { pubkey: player0Pda, isSigner: false, isWritable: true },
+        { pubkey: player1Pda, isSigner: false, isWritable: true },
       ])
       .signers([sessionKey2])
       .rpc();
@@ -312,29 +627,776 @@ describe("deadmint", () => {
     const game = await program.account.game.fetch(gamePda);
     expect(game.status).to.equal(2); // Finished
     expect(game.winner.toBase58()).to.equal(sessionKey2.publicKey.toBase58());
+    expect(game.placements.slice(0, 2)).to.deep.equal([2, 1]);
+
+    // Clients can follow the game from events instead of diffing accounts
+    await new Promise((r) => setTimeout(r, 1000));
+    await program.removeEventListener(listener);
+    expect(ended?.winner.toBase58()).to.equal(sessionKey2.publicKey.toBase58());
     console.log("  ✓ Game ended. Winner: Player 2");
   });
 
-  it("winner claims prize", async () => {
-    const balBefore = await connection.getBalance(sessionKey2.publicKey);
+  it("winner claims prize to the wallet that paid the wager", async () => {
+    const balBefore = await connection.getBalance(player2Wallet.publicKey);
+    const treasuryBefore = await program.account.treasury.fetch(treasuryPda);
 
     await program.methods
       .claimPrize()
       .accounts({
         game: gamePda,
         player: player1Pda,
-        winner: sessionKey2.publicKey,
+        treasury: treasuryPda,
+        payer: player2Wallet.publicKey,
       })
-      .signers([sessionKey2])
       .rpc();
 
     const game = await program.account.game.fetch(gamePda);
     expect(game.status).to.equal(3); // Claimed
     expect(game.prizePool.toString()).to.equal("0");
 
-    const balAfter = await connection.getBalance(sessionKey2.publicKey);
+    const balAfter = await connection.getBalance(player2Wallet.publicKey);
     const payout = balAfter - balBefore;
     expect(payout).to.be.greaterThan(0.09 * LAMPORTS_PER_SOL);
+
+    // 3% platform fee accrued to the treasury
+    const treasuryAfter = await program.account.treasury.fetch(treasuryPda);
+    const fee = treasuryAfter.totalFeesCollected.sub(treasuryBefore.totalFeesCollected);
+    expect(fee.toString()).to.equal(entryFee.mul(new anchor.BN(2)).muln(gameParams.platformFeeBps).divn(10_000).toString());
     console.log("  ✓ Winner received", (payout / LAMPORTS_PER_SOL).toFixed(4), "SOL");
   });
+
+  it("admin withdraws accrued SOL fees", async () => {
+    const amount = entryFee.mul(new anchor.BN(2)).muln(gameParams.platformFeeBps).divn(10_000);
+    const treasuryBefore = await program.account.treasury.fetch(treasuryPda);
+    const balBefore = await connection.getBalance(player2Wallet.publicKey);
+
+    await program.methods
+      .withdrawFees(amount)
+      .accounts({
+        treasury: treasuryPda,
+        admin: payer.publicKey,
+        recipient: player2Wallet.publicKey,
+      })
+      .rpc();
+
+    const balAfter = await connection.getBalance(player2Wallet.publicKey);
+    expect(balAfter - balBefore).to.equal(amount.toNumber());
+    const treasuryAfter = await program.account.treasury.fetch(treasuryPda);
+    expect(treasuryAfter.totalFeesWithdrawn.sub(treasuryBefore.totalFeesWithdrawn).toString()).to.equal(amount.toString());
+  });
+
+  it("rejects fee withdrawals by anyone but the admin", async () => {
+    try {
+      await program.methods
+        .withdrawFees(new anchor.BN(1))
+        .accounts({
+          treasury: treasuryPda,
+          config: configPda,
+          admin: player2Wallet.publicKey,
+          recipient: player2Wallet.publicKey,
+        })
+        .signers([player2Wallet])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code || e.message).to.include("Unauthorized");
+    }
+  });
+
+  it("rejects a withdrawal that would dip into the treasury's rent reserve", async () => {
+    // The whole balance includes the rent-exempt reserve, so it is never available
+    const balance = await connection.getBalance(treasuryPda);
+    try {
+      await program.methods
+        .withdrawFees(new anchor.BN(balance))
+        .accounts({
+          treasury: treasuryPda,
+          config: configPda,
+          admin: payer.publicKey,
+          recipient: payer.publicKey,
+        })
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code || e.message).to.include("InsufficientFees");
+    }
+    expect(await connection.getBalance(treasuryPda)).to.equal(balance);
+  });
+
+  it("closes player and game accounts after settlement", async () => {
+    for (const [playerPda, wallet] of [
+      [player0Pda, payer.publicKey],
+      [player1Pda, player2Wallet.publicKey],
+    ]) {
+      await program.methods
+        .closePlayer()
+        .accounts({ game: gamePda, player: playerPda, payer: wallet })
+        .rpc();
+      expect(await connection.getAccountInfo(playerPda)).to.equal(null);
+    }
+
+    await program.methods
+      .closeGame()
+      .accounts({ game: gamePda, authority: payer.publicKey })
+      .rpc();
+    expect(await connection.getAccountInfo(gamePda)).to.equal(null);
+  });
+
+  it("authority cancels an unfilled lobby and refunds the wager", async () => {
+    const lobbyId = new anchor.BN(Date.now() + 1);
+    const [lobbyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), lobbyId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [lobbyPlayerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("player"), lobbyPda.toBuffer(), Buffer.from([0])],
+      program.programId
+    );
+
+    await program.methods
+      .initializeGame(lobbyId, gameParams)
+      .accounts({
+        game: lobbyPda,
+        config: configPda,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await program.methods
+      .joinGame(sessionKey2.publicKey, Array.from(randomBytes(32)))
+      .accounts({
+        game: lobbyPda,
+        config: configPda,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        player: lobbyPlayerPda,
+        payer: player2Wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player2Wallet])
+      .rpc();
+
+    const balBefore = await connection.getBalance(player2Wallet.publicKey);
+
+    await program.methods
+      .cancelGame()
+      .accounts({
+        game: lobbyPda,
+        authority: payer.publicKey,
+        caller: payer.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: lobbyPlayerPda, isSigner: false, isWritable: true },
+        { pubkey: player2Wallet.publicKey, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    expect(await connection.getAccountInfo(lobbyPda)).to.equal(null);
+    expect(await connection.getAccountInfo(lobbyPlayerPda)).to.equal(null);
+
+    // Wager plus Player account rent returned to the wallet that joined
+    const balAfter = await connection.getBalance(player2Wallet.publicKey);
+    expect(balAfter - balBefore).to.be.greaterThan(entryFee.toNumber());
+  });
+
+  it("generates a seeded arena mirrored across both axes", async () => {
+    const mapGameId = new anchor.BN(Date.now() + 2);
+    const [mapGamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), mapGameId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const map = { template: 2, blockDensity: 60, lootDensity: 20, powerupDensity: 10, symmetry: 2 };
+
+    await program.methods
+      .initializeGame(mapGameId, { ...gameParams, map })
+      .accounts({
+        game: mapGamePda,
+        config: configPda,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const game = await program.account.game.fetch(mapGamePda);
+    const cell = (x: number, y: number) => game.cells[y * 13 + x];
+    for (let y = 0; y < 11; y++) {
+      for (let x = 0; x < 13; x++) {
+        expect(cell(x, y)).to.equal(cell(12 - x, 10 - y));
+      }
+    }
+    expect(cell(1, 1)).to.equal(0); // spawns stay clear
+    expect(cell(11, 1)).to.equal(0);
+
+    // Densities above 100% are rejected
+    const badId = new anchor.BN(Date.now() + 3);
+    const [badPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), badId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    try {
+      await program.methods
+        .initializeGame(badId, { ...gameParams, map: { ...map, lootDensity: 80, powerupDensity: 30 } })
+        .accounts({
+          game: badPda,
+          config: configPda,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code || e.message).to.include("InvalidMapOptions");
+    }
+  });
+
+  it("authority uploads a custom map with validation and a map hash, until someone joins", async () => {
+    const customId = new anchor.BN(Date.now() + 4);
+    const [customPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), customId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .initializeGame(customId, gameParams)
+      .accounts({
+        game: customPda,
+        config: configPda,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Open arena: border walls, a wall line down the middle with a gap at y = 5
+    const cells = Array.from({ length: 143 }, (_, i) => {
+      const x = i % 13;
+      const y = Math.floor(i / 13);
+      const border = x === 0 || x === 12 || y === 0 || y === 10;
+      return border || (x === 6 && y !== 5) ? 1 : 0;
+    });
+    const layout = {
+      cells,
+      powerupTypes: new Array(143).fill(0),
+      spawns: [{ x: 1, y: 1 }, { x: 11, y: 9 }],
+    };
+    const setMap = (l: typeof layout) =>
+      program.methods
+        .setMap(l)
+        .accounts({ game: customPda, config: configPda, authority: payer.publicKey })
+        .rpc();
+
+    // Closing the gap walls the spawns off from each other
+    const sealed = [...cells];
+    sealed[5 * 13 + 6] = 1;
+    try {
+      await setMap({ ...layout, cells: sealed });
+      expect.fail("Should have thrown");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code || e.message).to.include("SpawnUnreachable");
+    }
+
+    // A hole in the border is rejected
+    const holed = [...cells];
+    holed[6] = 0;
+    try {
+      await setMap({ ...layout, cells: holed });
+      expect.fail("Should have thrown");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code || e.message).to.include("MapBorderOpen");
+    }
+
+    // Blocks on both sides of a spawn leave nowhere to hide from its first bomb
+    const boxed = [...cells];
+    boxed[1 * 13 + 2] = 2;
+    boxed[2 * 13 + 1] = 2;
+    await expectError(setMap({ ...layout, cells: boxed }), "SpawnBoxedIn");
+
+    // Only the game's authority can replace its map
+    await expectError(
+      program.methods
+        .setMap(layout)
+        .accounts({ game: customPda, config: configPda, authority: player2Wallet.publicKey })
+        .signers([player2Wallet])
+        .rpc(),
+      "Unauthorized"
+    );
+
+    const before = await program.account.game.fetch(customPda);
+    await setMap(layout);
+    const game = await program.account.game.fetch(customPda);
+    expect(Array.from(game.cells)).to.deep.equal(cells);
+    expect(game.spawns[1]).to.deep.equal({ x: 11, y: 9 });
+    expect(Buffer.from(game.mapHash).equals(Buffer.from(before.mapHash))).to.equal(false);
+
+    // Once someone has joined, they already stand on the uploaded spawns
+    await program.methods
+      .joinGame(payer.publicKey, Array.from(randomBytes(32)))
+      .accounts({
+        game: customPda,
+        config: configPda,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        player: playerPdaFor(customPda, 0),
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await expectError(setMap(layout), "MapLocked");
+  });
+
+  it("creates a larger arena with corner spawns computed from its size", async () => {
+    const bigId = new anchor.BN(Date.now() + 5);
+    const [bigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), bigId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .initializeGame(bigId, { ...gameParams, gridWidth: 21, gridHeight: 15 })
+      .accounts({
+        game: bigPda,
+        config: configPda,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const game = await program.account.game.fetch(bigPda);
+    expect(game.gridWidth).to.equal(21);
+    expect(game.gridHeight).to.equal(15);
+    expect(game.cells.length).to.equal(21 * 15);
+    expect(game.spawns[1]).to.deep.equal({ x: 19, y: 1 });
+    expect(game.cells[13 * 21 + 19]).to.equal(0); // bottom-right spawn is clear
+
+    // Even sides don't line up with the pillar pattern
+    const evenId = new anchor.BN(Date.now() + 6);
+    const [evenPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), evenId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    try {
+      await program.methods
+        .initializeGame(evenId, { ...gameParams, gridWidth: 12 })
+        .accounts({
+          game: evenPda,
+          config: configPda,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code || e.message).to.include("InvalidGridSize");
+    }
+  });
+
+  it("sizes spawns and bomb slots for an 8-player lobby", async () => {
+    const partyId = new anchor.BN(Date.now() + 7);
+    const [partyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), partyId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .initializeGame(partyId, { ...gameParams, maxPlayers: 8, gridWidth: 21, gridHeight: 15 })
+      .accounts({
+        game: partyPda,
+        config: configPda,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const game = await program.account.game.fetch(partyPda);
+    // Corners, then the middle of the top/bottom rows and left/right columns
+    expect(game.spawns).to.deep.equal([
+      { x: 1, y: 1 }, { x: 19, y: 1 }, { x: 1, y: 13 }, { x: 19, y: 13 },
+      { x: 10, y: 1 }, { x: 10, y: 13 }, { x: 1, y: 7 }, { x: 19, y: 7 },
+    ]);
+    for (const { x, y } of game.spawns) {
+      expect(game.cells[y * 21 + x]).to.equal(0);
+    }
+    // max_players × max_bombs_cap
+    expect(game.bombs.length).to.equal(8 * gameParams.maxBombsCap);
+  });
+
+  for (const [name, tokenProgram] of [
+    ["SPL Token", TOKEN_PROGRAM_ID],
+    ["Token-2022", TOKEN_2022_PROGRAM_ID],
+  ] as const) {
+    it(`stakes a ${name} mint through join, claim and cancel`, async () => {
+      const wager = 1_000_000;
+      const mint = await createMint(connection, payer.payer, payer.publicKey, null, 6, undefined, undefined, tokenProgram);
+      const wallets = [payer.payer, player2Wallet];
+      const tokenAccounts: PublicKey[] = [];
+      for (const wallet of wallets) {
+        const { address } = await getOrCreateAssociatedTokenAccount(
+          connection, payer.payer, mint, wallet.publicKey, false, undefined, undefined, tokenProgram
+        );
+        await mintTo(connection, payer.payer, mint, address, payer.payer, 10 * wager, [], undefined, tokenProgram);
+        tokenAccounts.push(address);
+      }
+      const balanceOf = async (account: PublicKey) =>
+        Number((await getAccount(connection, account, undefined, tokenProgram)).amount);
+
+      const [treasuryVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury_vault"), mint.toBuffer()],
+        program.programId
+      );
+      const initTokenGame = (id: anchor.BN, accounts: { vault?: PublicKey; treasuryVault?: PublicKey }) =>
+        program.methods
+          .initializeGame(id, { ...gameParams, entryFee: new anchor.BN(wager), roundDuration: 1 })
+          .accounts({
+            game: gamePdaFor(id),
+            config: configPda,
+            payer: payer.publicKey,
+            mint,
+            vault: accounts.vault ?? null,
+            treasuryVault: accounts.treasuryVault ?? null,
+            tokenProgram,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      const vaultFor = (game: PublicKey) =>
+        PublicKey.findProgramAddressSync([Buffer.from("vault"), game.toBuffer()], program.programId)[0];
+      const join = (game: PublicKey, index: number) =>
+        program.methods
+          .joinGame(wallets[index].publicKey, Array.from(randomBytes(32)))
+          .accounts({
+            game,
+            config: configPda,
+            slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+            player: playerPdaFor(game, index),
+            payer: wallets[index].publicKey,
+            mint,
+            vault: vaultFor(game),
+            payerTokenAccount: tokenAccounts[index],
+            tokenProgram,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([wallets[index]])
+          .rpc();
+
+      const tokenId = new anchor.BN(Date.now() + 20);
+      const tokenGame = gamePdaFor(tokenId);
+
+      // A mint needs its game vault, and a treasury vault to take the fee in
+      for (const [accounts, code] of [
+        [{}, "MissingTokenAccounts"],
+        [{ vault: vaultFor(tokenGame) }, "MissingTreasuryVault"],
+      ] as const) {
+        try {
+          await initTokenGame(tokenId, accounts);
+          expect.fail("Should have thrown");
+        } catch (e: any) {
+          expect(e.error?.errorCode?.code || e.message).to.include(code);
+        }
+      }
+
+      await program.methods
+        .initializeTreasuryVault()
+        .accounts({
+          treasury: treasuryPda,
+          config: configPda,
+          admin: payer.publicKey,
+          mint,
+          treasuryVault,
+          tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await initTokenGame(tokenId, { vault: vaultFor(tokenGame), treasuryVault });
+      await join(tokenGame, 0);
+      await join(tokenGame, 1);
+      expect(await balanceOf(vaultFor(tokenGame))).to.equal(2 * wager);
+
+      // Both survive the 1-second round and split the pool net of the 3% fee
+      await new Promise((r) => setTimeout(r, 2000));
+      await checkGameEnd(tokenGame, [0, 1].map((i) => playerPdaFor(tokenGame, i)));
+      for (const index of [0, 1]) {
+        await program.methods
+          .claimPrize()
+          .accounts({
+            game: tokenGame,
+            player: playerPdaFor(tokenGame, index),
+            treasury: treasuryPda,
+            payer: wallets[index].publicKey,
+            mint,
+            vault: vaultFor(tokenGame),
+            payerTokenAccount: tokenAccounts[index],
+            treasuryVault,
+            tokenProgram,
+          })
+          .rpc();
+      }
+      const fee = (2 * wager * gameParams.platformFeeBps) / 10_000;
+      expect(await balanceOf(treasuryVault)).to.equal(fee);
+      expect(await balanceOf(vaultFor(tokenGame))).to.equal(0);
+      for (const account of tokenAccounts) {
+        expect(await balanceOf(account)).to.equal(10 * wager - wager + (2 * wager - fee) / 2);
+      }
+      expect((await program.account.game.fetch(tokenGame)).status).to.equal(3); // Claimed
+
+      // Cancelling a lobby refunds the wager in tokens and closes the vault
+      const lobbyId = new anchor.BN(Date.now() + 21);
+      const lobby = gamePdaFor(lobbyId);
+      await initTokenGame(lobbyId, { vault: vaultFor(lobby), treasuryVault });
+      const before = await balanceOf(tokenAccounts[0]);
+      await join(lobby, 0);
+      expect(await balanceOf(tokenAccounts[0])).to.equal(before - wager);
+      await program.methods
+        .cancelGame()
+        .accounts({
+          game: lobby,
+          authority: payer.publicKey,
+          caller: payer.publicKey,
+          mint,
+          vault: vaultFor(lobby),
+          authorityTokenAccount: tokenAccounts[0],
+          tokenProgram,
+        })
+        .remainingAccounts([
+          { pubkey: playerPdaFor(lobby, 0), isSigner: false, isWritable: true },
+          { pubkey: payer.publicKey, isSigner: false, isWritable: true },
+          { pubkey: tokenAccounts[0], isSigner: false, isWritable: true },
+        ])
+        .rpc();
+      expect(await balanceOf(tokenAccounts[0])).to.equal(before);
+      expect(await connection.getAccountInfo(lobby)).to.equal(null);
+      expect(await connection.getAccountInfo(vaultFor(lobby))).to.equal(null);
+
+      // The config's entry-fee range is in lamports and doesn't bound token wagers
+      const bigId = new anchor.BN(Date.now() + 22);
+      const bigLobby = gamePdaFor(bigId);
+      await program.methods
+        .initializeGame(bigId, { ...gameParams, entryFee: new anchor.BN(101 * LAMPORTS_PER_SOL) })
+        .accounts({
+          game: bigLobby,
+          config: configPda,
+          payer: payer.publicKey,
+          mint,
+          vault: vaultFor(bigLobby),
+          treasuryVault,
+          tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect((await program.account.game.fetch(bigLobby)).entryFee.toString()).to.equal(
+        new anchor.BN(101 * LAMPORTS_PER_SOL).toString()
+      );
+    });
+  }
+
+  it("compacts the lobby when a middle player leaves", async () => {
+    const third = Keypair.generate();
+    const fundSig = await connection.requestAirdrop(third.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(fundSig);
+    const { game: lobbyPda, players } = await startGame(
+      new anchor.BN(Date.now() + 30),
+      { ...gameParams, maxPlayers: 4 },
+      [payer.payer, player2Wallet, third]
+    );
+    const spawns = (await program.account.game.fetch(lobbyPda)).spawns;
+    const lastRent = await connection.getBalance(players[2]);
+    const balBefore = await connection.getBalance(player2Wallet.publicKey);
+
+    let left: any = null;
+    const listener = program.addEventListener("playerLeft", (event) => {
+      left = event;
+    });
+
+    // Index 1 leaves; index 2 is moved into its PDA
+    await program.methods
+      .leaveLobby()
+      .accounts({
+        game: lobbyPda,
+        player: players[1],
+        lastPlayer: players[2],
+        payer: player2Wallet.publicKey,
+      })
+      .signers([player2Wallet])
+      .rpc();
+
+    const moved = await program.account.player.fetch(players[1]);
+    expect(moved.payer.toBase58()).to.equal(third.publicKey.toBase58());
+    expect(moved.authority.toBase58()).to.equal(third.publicKey.toBase58());
+    expect(moved.playerIndex).to.equal(1);
+    expect({ x: moved.x, y: moved.y }).to.deep.equal(spawns[1]);
+    expect(await connection.getAccountInfo(players[2])).to.equal(null);
+
+    await new Promise((r) => setTimeout(r, 1000));
+    await program.removeEventListener(listener);
+    expect(left?.player.toBase58()).to.equal(player2Wallet.publicKey.toBase58());
+    expect([left?.movedFrom, left?.movedTo]).to.deep.equal([2, 1]);
+
+    // The leaver gets the wager back plus the rent of the closed PDA
+    const balAfter = await connection.getBalance(player2Wallet.publicKey);
+    expect(balAfter - balBefore).to.equal(entryFee.toNumber() + lastRent);
+    const game = await program.account.game.fetch(lobbyPda);
+    expect(game.currentPlayers).to.equal(2);
+
+    // The next joiner takes the freed index 2
+    await program.methods
+      .joinGame(player2Wallet.publicKey, Array.from(randomBytes(32)))
+      .accounts({
+        game: lobbyPda,
+        config: configPda,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        player: players[2],
+        payer: player2Wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player2Wallet])
+      .rpc();
+    const rejoined = await program.account.player.fetch(players[2]);
+    expect(rejoined.playerIndex).to.equal(2);
+    expect(rejoined.payer.toBase58()).to.equal(player2Wallet.publicKey.toBase58());
+    expect({ x: rejoined.x, y: rejoined.y }).to.deep.equal(spawns[2]);
+  });
+
+  it("credits a kill bounty to the bomb owner, paid out through claim_loot", async () => {
+    // Open arena: A spawns at (1,1), B right below it at (1,2)
+    const cells = Array.from({ length: 143 }, (_, i) => {
+      const x = i % 13;
+      const y = Math.floor(i / 13);
+      return x === 0 || x === 12 || y === 0 || y === 10 ? 1 : 0;
+    });
+    const layout = { cells, powerupTypes: new Array(143).fill(0), spawns: [{ x: 1, y: 1 }, { x: 1, y: 2 }] };
+    const bountyBps = 5_000;
+    const [killer, victim] = [player2Wallet, payer.payer];
+    const { game: bountyPda, players } = await startGame(
+      new anchor.BN(Date.now() + 40),
+      { ...gameParams, bountyBps },
+      [killer, victim],
+      layout
+    );
+    const actAs = (wallet: Keypair, index: number) => ({
+      game: bountyPda,
+      config: configPda,
+      player: players[index],
+      authority: wallet.publicKey,
+    });
+
+    // A drops a bomb and steps out of its range: right, then down to (2,2)
+    await program.methods.placeBomb().accounts(actAs(killer, 0)).signers([killer]).rpc();
+    await program.methods.movePlayer(3).accounts(actAs(killer, 0)).signers([killer]).rpc();
+    await waitSlots(connection, 3);
+    await program.methods.movePlayer(1).accounts(actAs(killer, 0)).signers([killer]).rpc();
+
+    const bomb = (await program.account.game.fetch(bountyPda)).bombs[0];
+    expect(bomb.ownerIndex).to.equal(0);
+    const fuseExpiry = bomb.placedAtSlot.toNumber() + bomb.fuseSlots;
+    await waitSlots(connection, Math.max(0, fuseExpiry - (await connection.getSlot())) + 1);
+    await program.methods
+      .detonateBomb(0)
+      .accounts({
+        game: bountyPda,
+        config: configPda,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        authority: payer.publicKey,
+      })
+      .remainingAccounts(players.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+
+    // Half of B's wager moves out of the pool into A's loot balance
+    const bounty = entryFee.muln(bountyBps).divn(10_000);
+    const a = await program.account.player.fetch(players[0]);
+    const b = await program.account.player.fetch(players[1]);
+    expect(b.alive).to.equal(false);
+    expect(a.kills).to.equal(1);
+    expect(a.collectedSol.toString()).to.equal(bounty.toString());
+    const game = await program.account.game.fetch(bountyPda);
+    expect(game.prizePool.toString()).to.equal(entryFee.muln(2).sub(bounty).toString());
+
+    await checkGameEnd(bountyPda, players);
+    const balBefore = await connection.getBalance(killer.publicKey);
+    await program.methods
+      .claimLoot()
+      .accounts({ game: bountyPda, player: players[0], payer: killer.publicKey })
+      .rpc();
+    expect((await connection.getBalance(killer.publicKey)) - balBefore).to.equal(bounty.toNumber());
+    expect((await program.account.player.fetch(players[0])).collectedSol.toString()).to.equal("0");
+  });
+
+  // Free game, ended by the round timer with both players alive
+  const freeId = new anchor.BN(Date.now() + 8);
+  let freePlayers: PublicKey[] = [];
+
+  it("settles a free game straight to claimed, since nothing can be paid out", async () => {
+    const started = await startGame(
+      freeId,
+      { ...gameParams, entryFee: new anchor.BN(0), roundDuration: 1 },
+      [payer.payer, player2Wallet]
+    );
+    freePlayers = started.players;
+
+    await new Promise((r) => setTimeout(r, 2000));
+    await checkGameEnd(started.game, freePlayers);
+
+    const game = await program.account.game.fetch(started.game);
+    expect(game.payoutPool.toString()).to.equal("0");
+    expect(game.placements.slice(0, 2)).to.deep.equal([1, 1]);
+    expect(game.status).to.equal(3); // Claimed — no claim_prize needed
+  });
+
+  it("splits the pool between the survivors of a timed-out round", async () => {
+    // Three players, nobody moves: all alive when the timer runs out, all tied for 1st
+    const third = Keypair.generate();
+    const fundSig = await connection.requestAirdrop(third.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(fundSig);
+    const wallets = [payer.payer, player2Wallet, third];
+    const { game: drawPda, players } = await startGame(
+      new anchor.BN(Date.now() + 9),
+      { ...gameParams, maxPlayers: 3, roundDuration: 1, payoutBps: [7_000, 3_000, 0] },
+      wallets
+    );
+
+    await new Promise((r) => setTimeout(r, 2000));
+    await checkGameEnd(drawPda, players);
+
+    let game = await program.account.game.fetch(drawPda);
+    expect(game.status).to.equal(2); // Finished
+    expect(game.placements.slice(0, 3)).to.deep.equal([1, 1, 1]);
+    const payoutPool = game.payoutPool.toNumber();
+
+    // Every place is shared, so each survivor gets a third of the whole pool
+    for (const [index, wallet] of wallets.entries()) {
+      const balBefore = await connection.getBalance(third.publicKey);
+      await program.methods
+        .claimPrize()
+        .accounts({
+          game: drawPda,
+          player: players[index],
+          treasury: treasuryPda,
+          payer: wallet.publicKey,
+        })
+        .rpc();
+      if (wallet === third) {
+        // Last claimant also takes the rounding dust
+        const payout = (await connection.getBalance(third.publicKey)) - balBefore;
+        expect(payout).to.be.within(Math.floor(payoutPool / 3), Math.floor(payoutPool / 3) + 2);
+      }
+    }
+
+    game = await program.account.game.fetch(drawPda);
+    expect(game.status).to.equal(3); // Claimed
+    expect(game.prizePool.toString()).to.equal("0");
+  });
+
+  it("closes a free game's accounts and returns their rent", async () => {
+    const freePda = gamePdaFor(freeId);
+    const balBefore = await connection.getBalance(player2Wallet.publicKey);
+
+    for (const [playerPda, wallet] of [
+      [freePlayers[0], payer.publicKey],
+      [freePlayers[1], player2Wallet.publicKey],
+    ]) {
+      await program.methods
+        .closePlayer()
+        .accounts({ game: freePda, player: playerPda, payer: wallet })
+        .rpc();
+      expect(await connection.getAccountInfo(playerPda)).to.equal(null);
+    }
+    expect(await connection.getBalance(player2Wallet.publicKey)).to.be.greaterThan(balBefore);
+
+    await program.methods
+      .closeGame()
+      .accounts({ game: freePda, authority: payer.publicKey })
+      .rpc();
+    expect(await connection.getAccountInfo(freePda)).to.equal(null);
+  });
 });